@@ -0,0 +1,910 @@
+//! Parsing of tsc command lines into compiler options, layering them over a configuration file
+//! as tsc does, and rendering compiler options back into a command line.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::{CompilerOptions, TsConfig, WatchOptions};
+
+/// The compiler options tsc accepts as flags, to look them up case-insensitively.
+///
+/// Watch options such as `watchFile` are left out, since tsc reads their flags into
+/// `watchOptions`.
+const COMPILER_OPTIONS: &[&str] = &[
+    "allowArbitraryExtensions",
+    "allowImportingTsExtensions",
+    "allowJs",
+    "allowSyntheticDefaultImports",
+    "allowUmdGlobalAccess",
+    "allowUnreachableCode",
+    "allowUnusedLabels",
+    "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
+    "baseUrl",
+    "charset",
+    "checkJs",
+    "composite",
+    "customConditions",
+    "declaration",
+    "declarationDir",
+    "declarationMap",
+    "diagnostics",
+    "disableReferencedProjectLoad",
+    "disableSizeLimit",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "downlevelIteration",
+    "emitBOM",
+    "emitDeclarationOnly",
+    "emitDecoratorMetadata",
+    "erasableSyntaxOnly",
+    "esModuleInterop",
+    "exactOptionalPropertyTypes",
+    "experimentalDecorators",
+    "explainFiles",
+    "extendedDiagnostics",
+    "forceConsistentCasingInFileNames",
+    "generateCpuProfile",
+    "generateTrace",
+    "ignoreDeprecations",
+    "importHelpers",
+    "importsNotUsedAsValues",
+    "incremental",
+    "inlineSourceMap",
+    "inlineSources",
+    "isolatedDeclarations",
+    "isolatedModules",
+    "jsx",
+    "jsxFactory",
+    "jsxFragmentFactory",
+    "jsxImportSource",
+    "keyofStringsOnly",
+    "lib",
+    "libReplacement",
+    "listEmittedFiles",
+    "listFiles",
+    "mapRoot",
+    "maxNodeModuleJsDepth",
+    "module",
+    "moduleDetection",
+    "moduleResolution",
+    "moduleSuffixes",
+    "newLine",
+    "noCheck",
+    "noEmit",
+    "noEmitHelpers",
+    "noEmitOnError",
+    "noErrorTruncation",
+    "noFallthroughCasesInSwitch",
+    "noImplicitAny",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noImplicitThis",
+    "noImplicitUseStrict",
+    "noLib",
+    "noPropertyAccessFromIndexSignature",
+    "noResolve",
+    "noStrictGenericChecks",
+    "noUncheckedIndexedAccess",
+    "noUncheckedSideEffectImports",
+    "noUnusedLocals",
+    "noUnusedParameters",
+    "out",
+    "outDir",
+    "outFile",
+    "paths",
+    "plugins",
+    "preserveConstEnums",
+    "preserveSymlinks",
+    "preserveValueImports",
+    "preserveWatchOutput",
+    "pretty",
+    "reactNamespace",
+    "removeComments",
+    "resolveJsonModule",
+    "resolvePackageJsonExports",
+    "resolvePackageJsonImports",
+    "rewriteRelativeImportExtensions",
+    "rootDir",
+    "rootDirs",
+    "skipDefaultLibCheck",
+    "skipLibCheck",
+    "sourceMap",
+    "sourceRoot",
+    "stopBuildOnErrors",
+    "strict",
+    "strictBindCallApply",
+    "strictBuiltinIteratorReturn",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "stripInternal",
+    "suppressExcessPropertyErrors",
+    "suppressImplicitAnyIndexErrors",
+    "target",
+    "traceResolution",
+    "tsBuildInfoFile",
+    "typeRoots",
+    "types",
+    "useDefineForClassFields",
+    "useUnknownInCatchVariables",
+    "verbatimModuleSyntax",
+];
+
+/// Flags tsc reads into `watchOptions` rather than `compilerOptions`.
+const WATCH_OPTIONS: &[&str] = &[
+    "excludeDirectories",
+    "excludeFiles",
+    "fallbackPolling",
+    "synchronousWatchDirectory",
+    "watchDirectory",
+    "watchFile",
+];
+
+/// Options tsc only accepts in configuration files, or set to `null` on the command line. Those
+/// that are boolean may also be set to `false`.
+const CONFIG_FILE_ONLY_OPTIONS: &[&str] = &[
+    "composite",
+    "disableReferencedProjectLoad",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "paths",
+    "plugins",
+    "rootDirs",
+];
+
+/// Flags that drive tsc itself rather than set options.
+const COMMAND_FLAGS: &[&str] = &["build", "help", "locale", "watch"];
+
+/// Flags that drive tsc itself, but do not exist after `--build`.
+const NON_BUILD_FLAGS: &[&str] = &[
+    "all",
+    "init",
+    "listFilesOnly",
+    "project",
+    "showConfig",
+    "version",
+];
+
+/// Flags that only exist after `--build`.
+const BUILD_FLAGS: &[&str] = &["clean", "dry", "force", "verbose"];
+
+/// Single-letter aliases of flags.
+const SHORT_NAMES: &[(&str, &str)] = &[
+    ("?", "help"),
+    ("b", "build"),
+    ("d", "declaration"),
+    ("h", "help"),
+    ("i", "incremental"),
+    ("m", "module"),
+    ("p", "project"),
+    ("t", "target"),
+    ("v", "version"),
+    ("w", "watch"),
+];
+
+/// Single-letter aliases of flags after `--build`, some of which mean something else without it.
+const BUILD_SHORT_NAMES: &[(&str, &str)] = &[
+    ("?", "help"),
+    ("d", "dry"),
+    ("f", "force"),
+    ("h", "help"),
+    ("i", "incremental"),
+    ("v", "verbose"),
+    ("w", "watch"),
+];
+
+/// An error in a tsc command line.
+///
+/// This is non-exhaustive, and may be extended in the future.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CommandLineError {
+    /// A flag that is not a known option, as written.
+    #[error("Unknown compiler option '{0}'")]
+    UnknownOption(String),
+    #[error("Compiler option '{0}' expects an argument")]
+    MissingArgument(String),
+    #[error("Invalid value for '{option}': {message}")]
+    InvalidValue { option: String, message: String },
+    /// An option such as `paths`, which can only be set to `null` on the command line.
+    #[error("Option '{0}' can only be specified in 'tsconfig.json' file or set to 'null' on command line")]
+    ConfigFileOnly(String),
+    /// A boolean option such as `composite`, which can only be set to `false` or `null` on the
+    /// command line.
+    #[error("Option '{0}' can only be specified in 'tsconfig.json' file or set to 'false' or 'null' on command line")]
+    ConfigFileOnlyBoolean(String),
+    #[error("Option 'project' cannot be mixed with source files on a command line")]
+    ProjectWithFiles,
+    #[error("Option '--build' must be the first command line argument")]
+    BuildNotFirst,
+}
+
+/// A tsc command line, with the compiler options it sets apart from the flags that drive tsc.
+///
+/// Every flag tsc accepts is parsed: those that make tsc do something other than compile, such as
+/// `--help` or `--showConfig`, are kept in their own fields for the caller to act on.
+///
+/// ## Example
+/// ```
+/// use tsconfig::{CommandLine, Target};
+///
+/// let command_line = CommandLine::parse(["-p", "tsconfig.build.json", "--strict", "--target", "es2022"]).unwrap();
+///
+/// assert_eq!(command_line.project.as_deref(), Some("tsconfig.build.json"));
+/// assert_eq!(command_line.options.strict, Some(true));
+/// assert_eq!(command_line.options.target, Some(Target::Es2022));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CommandLine {
+    /// `--build` (`-b`): build the projects listed in `files`, and those they reference.
+    pub build: bool,
+    /// The flags that only exist after `--build`.
+    pub build_options: BuildOptions,
+    /// `--project` (`-p`): the configuration file to use, or the directory containing it.
+    pub project: Option<String>,
+    /// `--watch` (`-w`).
+    pub watch: bool,
+    /// `--help` (`-h`, `-?`): print help instead of compiling.
+    pub help: bool,
+    /// `--all`: list every option in the help.
+    pub all: bool,
+    /// `--version` (`-v`): print the version of tsc instead of compiling.
+    pub version: bool,
+    /// `--init`: write a new tsconfig.json instead of compiling.
+    pub init: bool,
+    /// `--showConfig`: print the final configuration instead of compiling.
+    pub show_config: bool,
+    /// `--listFilesOnly`: print the files that are part of the compilation instead of compiling.
+    pub list_files_only: bool,
+    /// `--locale`: the language of tsc's messages, e.g. `en`.
+    pub locale: Option<String>,
+    pub options: CompilerOptions,
+    /// Flags such as `--watchFile`, which tsc reads into `watchOptions`.
+    pub watch_options: WatchOptions,
+    /// Options set to `null`, which unsets them in the configuration file.
+    pub unset: Vec<String>,
+    /// The arguments that are not flags: source files, or projects with `--build`.
+    pub files: Vec<String>,
+}
+
+/// The flags that only exist after `tsc --build`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildOptions {
+    /// `--verbose` (`-v`): explain what is built and why.
+    pub verbose: bool,
+    /// `--dry` (`-d`): show what would be built without building it.
+    pub dry: bool,
+    /// `--force` (`-f`): build every project, even those that are up to date.
+    pub force: bool,
+    /// `--clean`: delete the outputs of the projects instead of building them.
+    pub clean: bool,
+}
+
+impl CommandLine {
+    /// Parses the arguments of tsc, without the program name.
+    ///
+    /// As with tsc, flags are case-insensitive and may start with `-` or `--`, boolean flags may
+    /// be followed by `true` or `false`, lists are separated by commas, and any option may be set
+    /// to `null` to unset it. Options tsc only accepts in configuration files, such as `paths`,
+    /// can only be unset, or turned off for booleans such as `composite`. Response files
+    /// (`@args.txt`) are not read.
+    pub fn parse<I, S>(args: I) -> Result<Self, CommandLineError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args: Vec<String> = args.into_iter().map(|a| a.as_ref().to_string()).collect();
+        let mut command_line = CommandLine::default();
+        let mut options = Map::new();
+        let mut watch_options = Map::new();
+
+        let mut i = 0;
+        while i < args.len() {
+            let arg = &args[i];
+            i += 1;
+            let flag = match arg.strip_prefix('-') {
+                Some(flag) => flag.strip_prefix('-').unwrap_or(flag),
+                None => {
+                    command_line.files.push(arg.clone());
+                    continue;
+                }
+            };
+            let name = canonical_name(flag, command_line.build)
+                .ok_or_else(|| CommandLineError::UnknownOption(arg.clone()))?;
+
+            match name {
+                "build" if i == 1 => command_line.build = true,
+                "build" => return Err(CommandLineError::BuildNotFirst),
+                "project" => command_line.project = Some(take_argument(&args, &mut i, name)?),
+                "watch" => command_line.watch = take_bool(&args, &mut i),
+                "help" => command_line.help = take_bool(&args, &mut i),
+                "all" => command_line.all = take_bool(&args, &mut i),
+                "version" => command_line.version = take_bool(&args, &mut i),
+                "init" => command_line.init = take_bool(&args, &mut i),
+                "showConfig" => command_line.show_config = take_bool(&args, &mut i),
+                "listFilesOnly" => command_line.list_files_only = take_bool(&args, &mut i),
+                "locale" => command_line.locale = Some(take_argument(&args, &mut i, name)?),
+                "verbose" => command_line.build_options.verbose = take_bool(&args, &mut i),
+                "dry" => command_line.build_options.dry = take_bool(&args, &mut i),
+                "force" => command_line.build_options.force = take_bool(&args, &mut i),
+                "clean" => command_line.build_options.clean = take_bool(&args, &mut i),
+                _ if WATCH_OPTIONS.contains(&name) => set_option::<WatchOptions>(
+                    &mut watch_options,
+                    &mut command_line.unset,
+                    name,
+                    &args,
+                    &mut i,
+                )?,
+                _ => set_option::<CompilerOptions>(
+                    &mut options,
+                    &mut command_line.unset,
+                    name,
+                    &args,
+                    &mut i,
+                )?,
+            }
+        }
+
+        if command_line.project.is_some() && !command_line.files.is_empty() {
+            return Err(CommandLineError::ProjectWithFiles);
+        }
+        command_line.options = serde_json::from_value(Value::Object(options))
+            .expect("every option was checked on its own");
+        command_line.watch_options = serde_json::from_value(Value::Object(watch_options))
+            .expect("every option was checked on its own");
+        Ok(command_line)
+    }
+}
+
+impl TsConfig {
+    /// Layers the options of a command line over those of this configuration, as tsc does with
+    /// `--project`: options set on the command line win, and those set to `null` are unset.
+    ///
+    /// Paths given on the command line are left as written, although tsc resolves them from the
+    /// working directory rather than from the configuration file.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{CommandLine, TsConfig};
+    ///
+    /// let mut config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "outDir": "dist"}}"#).unwrap();
+    /// config.apply_command_line(&CommandLine::parse(["--strict", "false", "--outDir", "null"]).unwrap());
+    ///
+    /// let options = config.compiler_options.unwrap();
+    /// assert_eq!(options.strict, Some(false));
+    /// assert_eq!(options.out_dir, None);
+    /// ```
+    pub fn apply_command_line(&mut self, command_line: &CommandLine) {
+        self.compiler_options = overlay(
+            &self.compiler_options,
+            &command_line.options,
+            &command_line.unset,
+        );
+        self.watch_options = overlay(
+            &self.watch_options,
+            &command_line.watch_options,
+            &command_line.unset,
+        );
+    }
+}
+
+impl CompilerOptions {
+    /// Renders the options as the tsc flags that set them, e.g. `--target ES2022`, `--lib
+    /// es2022,dom` or `--noEmit`, so that tsc can be run without a configuration file. Flags come
+    /// in the order the fields of [CompilerOptions] are declared.
+    ///
+    /// Options tsc only accepts in configuration files (`paths`, `plugins`, `rootDirs`, and
+    /// `composite` and the other project reference options unless they are `false`) and unknown
+    /// options in [CompilerOptions::extra] cannot be passed as flags, and are left out.
+    /// The deprecated watch options, such as `watchFile`, are rendered as the flags tsc reads into
+    /// `watchOptions`.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::TsConfig;
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"target": "es2022", "lib": ["es2022", "dom"], "noEmit": true}}"#).unwrap();
+    /// let args = config.compiler_options.unwrap().to_command_line();
+    ///
+    /// assert_eq!(args, ["--lib", "es2022,dom", "--noEmit", "--target", "ES2022"]);
+    /// ```
+    pub fn to_command_line(&self) -> Vec<String> {
+        let options = match serde_json::to_value(self) {
+            Ok(Value::Object(options)) => options,
+            _ => return Vec::new(),
+        };
+
+        let mut args = Vec::new();
+        for (name, value) in options {
+            let known =
+                COMPILER_OPTIONS.contains(&name.as_str()) || WATCH_OPTIONS.contains(&name.as_str());
+            let config_file_only = CONFIG_FILE_ONLY_OPTIONS.contains(&name.as_str());
+            if !known || (config_file_only && value != Value::Bool(false)) {
+                continue;
+            }
+            let argument = match value {
+                Value::Bool(true) => None,
+                Value::Bool(false) => Some("false".to_string()),
+                Value::String(s) => Some(s),
+                Value::Number(n) => Some(n.to_string()),
+                Value::Array(items) => Some(
+                    items
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
+                Value::Null | Value::Object(_) => continue,
+            };
+            args.push(format!("--{}", name));
+            args.extend(argument);
+        }
+        args
+    }
+}
+
+/// The options `overrides` sets, on top of those of `base`, without those in `unset`.
+fn overlay<T>(base: &Option<T>, overrides: &T, unset: &[String]) -> Option<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut merged = match base.as_ref().map(serde_json::to_value) {
+        Some(Ok(Value::Object(base))) => base,
+        _ => Map::new(),
+    };
+    if let Ok(Value::Object(overrides)) = serde_json::to_value(overrides) {
+        merged.extend(overrides);
+    }
+    for name in unset {
+        merged.remove(name);
+    }
+
+    if base.is_none() && merged.is_empty() {
+        return None;
+    }
+    Some(serde_json::from_value(Value::Object(merged)).expect("both sets of options are valid"))
+}
+
+/// The name of the option a flag stands for, without its dashes.
+fn canonical_name(flag: &str, build: bool) -> Option<&'static str> {
+    let flag = flag.to_lowercase();
+    let short_names = if build {
+        BUILD_SHORT_NAMES
+    } else {
+        SHORT_NAMES
+    };
+    if let Some((_, name)) = short_names.iter().find(|(short, _)| *short == flag) {
+        return Some(name);
+    }
+
+    let mode_flags = if build { BUILD_FLAGS } else { NON_BUILD_FLAGS };
+    COMMAND_FLAGS
+        .iter()
+        .chain(mode_flags)
+        .chain(WATCH_OPTIONS)
+        .chain(COMPILER_OPTIONS)
+        .find(|name| name.to_lowercase() == flag)
+        .copied()
+}
+
+/// Takes the argument of the flag `name`.
+fn take_argument(args: &[String], i: &mut usize, name: &str) -> Result<String, CommandLineError> {
+    let argument = args
+        .get(*i)
+        .ok_or_else(|| CommandLineError::MissingArgument(name.to_string()))?;
+    *i += 1;
+    Ok(argument.clone())
+}
+
+/// Takes the optional `true` or `false` after a boolean flag, which is `true` without one.
+fn take_bool(args: &[String], i: &mut usize) -> bool {
+    match args.get(*i).map(String::as_str) {
+        Some("false") => {
+            *i += 1;
+            false
+        }
+        Some("true") => {
+            *i += 1;
+            true
+        }
+        _ => true,
+    }
+}
+
+/// Reads the value of the option `name` of the `T` section from the arguments.
+fn set_option<T: DeserializeOwned>(
+    options: &mut Map<String, Value>,
+    unset: &mut Vec<String>,
+    name: &str,
+    args: &[String],
+    i: &mut usize,
+) -> Result<(), CommandLineError> {
+    let next = args.get(*i).map(String::as_str);
+    if next == Some("null") {
+        *i += 1;
+        options.remove(name);
+        unset.push(name.to_string());
+        return Ok(());
+    }
+
+    let is_boolean = accepts::<T>(name, Value::Bool(true)).is_ok();
+    let value = if CONFIG_FILE_ONLY_OPTIONS.contains(&name) {
+        // Those that are boolean may be turned off, but not on.
+        match (is_boolean, next) {
+            (true, Some("false")) => {
+                *i += 1;
+                Value::Bool(false)
+            }
+            (true, _) => {
+                return Err(CommandLineError::ConfigFileOnlyBoolean(name.to_string()));
+            }
+            (false, _) => return Err(CommandLineError::ConfigFileOnly(name.to_string())),
+        }
+    } else if is_boolean {
+        Value::Bool(take_bool(args, i))
+    } else {
+        parse_value::<T>(name, &take_argument(args, i, name)?)?
+    };
+    unset.retain(|unset| unset != name);
+    options.insert(name.to_string(), value);
+    Ok(())
+}
+
+/// Reads the argument of a non-boolean option as whichever of a string, a comma-separated list
+/// or a number the option takes.
+fn parse_value<T: DeserializeOwned>(name: &str, argument: &str) -> Result<Value, CommandLineError> {
+    let string = Value::from(argument);
+    let error = match accepts::<T>(name, string.clone()) {
+        Ok(()) => return Ok(string),
+        Err(error) => error,
+    };
+
+    // Empty items are kept, as in `--moduleSuffixes .ios,`, but an empty argument is an empty list.
+    let list = match argument.trim() {
+        "" => Value::Array(Vec::new()),
+        argument => Value::Array(argument.split(',').map(Value::from).collect()),
+    };
+    if accepts::<T>(name, list.clone()).is_ok() {
+        return Ok(list);
+    }
+    if let Ok(number) = argument.parse::<i64>() {
+        if accepts::<T>(name, Value::from(number)).is_ok() {
+            return Ok(Value::from(number));
+        }
+    }
+
+    Err(CommandLineError::InvalidValue {
+        option: name.to_string(),
+        message: error.to_string(),
+    })
+}
+
+/// Checks that the `T` section accepts `value` for the option `name`.
+fn accepts<T: DeserializeOwned>(name: &str, value: Value) -> serde_json::Result<()> {
+    let mut option = Map::new();
+    option.insert(name.to_string(), value);
+    serde_json::from_value::<T>(Value::Object(option)).map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Lib, ModuleResolutionMode, Target, WatchFileKind};
+
+    fn parse(args: &str) -> Result<CommandLine, CommandLineError> {
+        CommandLine::parse(args.split_whitespace())
+    }
+
+    #[test]
+    fn parses_compiler_options() {
+        let command_line = parse(
+            "--strict --noEmit false --TARGET ES2022 --lib es2022,dom -m nodenext src/index.ts",
+        )
+        .unwrap();
+        let options = command_line.options;
+
+        assert_eq!(options.strict, Some(true));
+        assert_eq!(options.no_emit, Some(false));
+        assert_eq!(options.target, Some(Target::Es2022));
+        assert_eq!(options.lib, Some(vec![Lib::Es2022, Lib::Dom]));
+        assert_eq!(options.module, Some(crate::Module::NodeNext));
+        assert_eq!(command_line.files, ["src/index.ts"]);
+    }
+
+    #[test]
+    fn parses_numbers_and_strings() {
+        let options = parse("--maxNodeModuleJsDepth 2 --outDir ./dist --moduleResolution Bundler")
+            .unwrap()
+            .options;
+
+        assert_eq!(options.max_node_module_js_depth, Some(2));
+        assert_eq!(options.out_dir, Some("./dist".to_string()));
+        assert_eq!(
+            options.module_resolution,
+            Some(ModuleResolutionMode::Bundler)
+        );
+    }
+
+    #[test]
+    fn separates_command_flags() {
+        let command_line = parse("-p ./packages/app -w --watchFile fixedPollingInterval").unwrap();
+        assert_eq!(command_line.project.as_deref(), Some("./packages/app"));
+        assert!(command_line.watch);
+        assert_eq!(
+            command_line.watch_options.watch_file,
+            Some(WatchFileKind::FixedPollingInterval)
+        );
+        assert_eq!(command_line.options.watch_file, None);
+
+        let command_line = parse("--build -v -d --force packages/a packages/b").unwrap();
+        assert!(command_line.build);
+        assert_eq!(
+            command_line.build_options,
+            BuildOptions {
+                verbose: true,
+                dry: true,
+                force: true,
+                clean: false
+            }
+        );
+        assert_eq!(command_line.options.declaration, None);
+        assert_eq!(command_line.files, ["packages/a", "packages/b"]);
+    }
+
+    #[test]
+    fn reports_invalid_command_lines() {
+        assert_eq!(
+            parse("--strictt").unwrap_err(),
+            CommandLineError::UnknownOption("--strictt".to_string())
+        );
+        assert_eq!(
+            parse("--verbose").unwrap_err(),
+            CommandLineError::UnknownOption("--verbose".to_string())
+        );
+        assert_eq!(
+            parse("--target").unwrap_err(),
+            CommandLineError::MissingArgument("target".to_string())
+        );
+        assert!(matches!(
+            parse("--jsx vue").unwrap_err(),
+            CommandLineError::InvalidValue { option, .. } if option == "jsx"
+        ));
+        assert_eq!(
+            parse("--strict --build").unwrap_err(),
+            CommandLineError::BuildNotFirst
+        );
+        assert_eq!(
+            parse("-p tsconfig.json index.ts").unwrap_err(),
+            CommandLineError::ProjectWithFiles
+        );
+    }
+
+    #[test]
+    fn only_unsets_config_file_only_options() {
+        assert_eq!(
+            parse("--paths {}").unwrap_err(),
+            CommandLineError::ConfigFileOnly("paths".to_string())
+        );
+        assert_eq!(
+            parse("--paths {}").unwrap_err().to_string(),
+            "Option 'paths' can only be specified in 'tsconfig.json' file or set to 'null' on command line"
+        );
+
+        let command_line = parse("--paths null --rootDirs null").unwrap();
+        assert_eq!(command_line.unset, ["paths", "rootDirs"]);
+    }
+
+    #[test]
+    fn only_turns_off_config_file_only_booleans() {
+        for flag in [
+            "composite",
+            "disableSolutionSearching",
+            "disableReferencedProjectLoad",
+            "disableSourceOfProjectReferenceRedirect",
+        ] {
+            assert_eq!(
+                CommandLine::parse([format!("--{}", flag)]).unwrap_err(),
+                CommandLineError::ConfigFileOnlyBoolean(flag.to_string())
+            );
+            assert_eq!(
+                CommandLine::parse([format!("--{}", flag), "true".to_string()]).unwrap_err(),
+                CommandLineError::ConfigFileOnlyBoolean(flag.to_string())
+            );
+        }
+
+        let command_line = parse("--composite false --disableSolutionSearching null").unwrap();
+        assert_eq!(command_line.options.composite, Some(false));
+        assert_eq!(command_line.unset, ["disableSolutionSearching"]);
+    }
+
+    #[test]
+    fn parses_flags_that_drive_tsc() {
+        let command_line =
+            parse("--locale en --listFilesOnly --showConfig -v -h --all --init").unwrap();
+        assert_eq!(command_line.locale.as_deref(), Some("en"));
+        assert!(command_line.list_files_only);
+        assert!(command_line.show_config);
+        assert!(command_line.version);
+        assert!(command_line.help);
+        assert!(command_line.all);
+        assert!(command_line.init);
+
+        let command_line = parse("-b -? --locale ja").unwrap();
+        assert!(command_line.help);
+        assert_eq!(command_line.locale.as_deref(), Some("ja"));
+        assert_eq!(
+            parse("-b --showConfig").unwrap_err(),
+            CommandLineError::UnknownOption("--showConfig".to_string())
+        );
+    }
+
+    #[test]
+    fn applies_command_line_over_config() {
+        let mut config = TsConfig::parse_str(
+            r#"{
+                "compilerOptions": {
+                    "strict": true,
+                    "target": "es2017",
+                    "paths": { "@/*": ["src/*"] },
+                    "outDir": "dist"
+                }
+            }"#,
+        )
+        .unwrap();
+        config.apply_command_line(
+            &parse("--target esnext --paths null --declaration --watchFile useFsEvents").unwrap(),
+        );
+
+        let options = config.compiler_options.unwrap();
+        assert_eq!(options.strict, Some(true));
+        assert_eq!(options.target, Some(Target::EsNext));
+        assert_eq!(options.declaration, Some(true));
+        assert_eq!(options.out_dir, Some("dist".to_string()));
+        assert!(options.paths.is_none());
+        assert_eq!(
+            config.watch_options.unwrap().watch_file,
+            Some(WatchFileKind::UseFsEvents)
+        );
+    }
+
+    #[test]
+    fn leaves_unset_sections_alone() {
+        let mut config = TsConfig::parse_str("{}").unwrap();
+        config.apply_command_line(&parse("--strict").unwrap());
+        assert!(config.compiler_options.is_some());
+        assert!(config.watch_options.is_none());
+    }
+
+    #[test]
+    fn renders_compiler_options() {
+        let options = parse("--strict --noEmit false --lib es2022,dom --maxNodeModuleJsDepth 2")
+            .unwrap()
+            .options;
+
+        assert_eq!(
+            options.to_command_line(),
+            [
+                "--lib",
+                "es2022,dom",
+                "--noEmit",
+                "false",
+                "--strict",
+                "--maxNodeModuleJsDepth",
+                "2"
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_out_options_flags_cannot_set() {
+        let config = TsConfig::parse_str(
+            r#"{"compilerOptions": {"paths": {"@/*": ["src/*"]}, "rootDirs": ["a", "b"], "strictt": true}}"#,
+        )
+        .unwrap();
+        assert!(config
+            .compiler_options
+            .unwrap()
+            .to_command_line()
+            .is_empty());
+    }
+
+    #[test]
+    fn renders_config_file_only_options_only_when_tsc_accepts_them() {
+        // The options tsc marks as `isTSConfigOnly`.
+        const TSC_CONFIG_FILE_ONLY: &[&str] = &[
+            "composite",
+            "disableReferencedProjectLoad",
+            "disableSolutionSearching",
+            "disableSourceOfProjectReferenceRedirect",
+            "paths",
+            "plugins",
+            "rootDirs",
+        ];
+        let config = TsConfig::parse_str(
+            r#"{"compilerOptions": {
+                "composite": true,
+                "disableSolutionSearching": false,
+                "disableReferencedProjectLoad": true,
+                "disableSourceOfProjectReferenceRedirect": true,
+                "paths": {"@/*": ["src/*"]},
+                "rootDirs": ["a", "b"],
+                "plugins": [{"name": "plugin"}],
+                "strict": true
+            }}"#,
+        )
+        .unwrap();
+        let args = config.compiler_options.unwrap().to_command_line();
+
+        assert_eq!(args, ["--strict", "--disableSolutionSearching", "false"]);
+        for (index, arg) in args.iter().enumerate() {
+            let name = arg.trim_start_matches('-');
+            if arg.starts_with("--") && TSC_CONFIG_FILE_ONLY.contains(&name) {
+                assert_eq!(
+                    args.get(index + 1).map(String::as_str),
+                    Some("false"),
+                    "{}",
+                    arg
+                );
+            }
+        }
+        assert!(CommandLine::parse(&args).is_ok());
+    }
+
+    #[test]
+    fn round_trips_through_command_line() {
+        let config = TsConfig::parse_str(include_str!("../test/tsconfig.complete.json")).unwrap();
+        let mut options = config.compiler_options.unwrap();
+        options.watch_file = Some(WatchFileKind::UseFsEventsOnParentDirectory);
+        let command_line = CommandLine::parse(options.to_command_line()).unwrap();
+
+        let expected: Map<String, Value> = match serde_json::to_value(&options).unwrap() {
+            Value::Object(options) => options
+                .into_iter()
+                .filter(|(name, _)| COMPILER_OPTIONS.contains(&name.as_str()))
+                .filter(|(name, value)| {
+                    !CONFIG_FILE_ONLY_OPTIONS.contains(&name.as_str()) || *value == false
+                })
+                .collect(),
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            serde_json::to_value(&command_line.options).unwrap(),
+            Value::Object(expected)
+        );
+        assert_eq!(
+            command_line.watch_options.watch_file,
+            Some(WatchFileKind::UseFsEventsOnParentDirectory)
+        );
+    }
+
+    #[test]
+    fn knows_every_compiler_option() {
+        for name in COMPILER_OPTIONS {
+            let options: CompilerOptions =
+                serde_json::from_value(serde_json::json!({ *name: null })).unwrap();
+            assert!(
+                options.extra.is_empty(),
+                "{} is not a compiler option",
+                name
+            );
+        }
+
+        let complete = crate::jsonc::parse(include_str!("../test/tsconfig.complete.json")).unwrap();
+        for name in complete["compilerOptions"].as_object().unwrap().keys() {
+            let name = name.as_str();
+            if COMMAND_FLAGS.contains(&name)
+                || NON_BUILD_FLAGS.contains(&name)
+                || WATCH_OPTIONS.contains(&name)
+            {
+                continue;
+            }
+            assert!(
+                COMPILER_OPTIONS.contains(&name),
+                "{} cannot be set from the command line",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,436 @@
+//! Rendering of configuration errors as tsc-style or codespan-style diagnostics.
+//!
+//! Enabled by the `diagnostics` cargo feature.
+//!
+//! ## Example
+//! ```
+//! use tsconfig::{Diagnostic, DiagnosticStyle, TsConfig};
+//!
+//! let json = "{\n  \"compilerOptions\": {\n    \"strict\": true\n    \"noEmit\": true\n  }\n}";
+//! let err = TsConfig::parse_str(json).unwrap_err();
+//!
+//! let rendered = Diagnostic::from_error(&err).render(DiagnosticStyle::Tsc, Some(json));
+//! assert_eq!(
+//!     rendered,
+//!     "4:5 - error TS1005: ',' expected.\n\n4     \"noEmit\": true\n      ~~~~~~~~\n"
+//! );
+//! ```
+
+use std::fmt::Write;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::{jsonc, CommandLineError, CompilerOptions, ConfigError, Location, SyntaxErrorKind};
+
+/// A message about a configuration file, with the TypeScript error code tsc uses for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The TypeScript error code, e.g. `5023` for `TS5023`.
+    pub code: u32,
+    /// The message, worded as tsc words it. Problems tsc words differently for each value, or does
+    /// not report, keep the wording of the error they come from.
+    pub message: String,
+    /// Where the problem is, if it can be pointed at.
+    pub location: Option<Location>,
+}
+
+/// How [Diagnostic::render] lays out a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStyle {
+    /// Like `tsc --pretty`: the position, the code, then the snippet underlined with `~`.
+    Tsc,
+    /// Like the `codespan-reporting` crate: a header, then the snippet in a gutter, underlined with `^`.
+    Codespan,
+}
+
+impl Diagnostic {
+    /// Describes a [ConfigError].
+    pub fn from_error(error: &ConfigError) -> Self {
+        let (code, message) = match error {
+            ConfigError::SyntaxError { error, .. } => {
+                let code = match error.kind {
+                    SyntaxErrorKind::Expected(_) => 1005,
+                    SyntaxErrorKind::PropertyAssignmentExpected => 1136,
+                    SyntaxErrorKind::ValueExpected | SyntaxErrorKind::InvalidNumber => 1109,
+                    SyntaxErrorKind::UnterminatedString => 1002,
+                    SyntaxErrorKind::UnterminatedComment => 1010,
+                    SyntaxErrorKind::DoubleQuotesExpected => 1327,
+                    SyntaxErrorKind::InvalidEscape => 1125,
+                    SyntaxErrorKind::InvalidCharacter => 1127,
+                    SyntaxErrorKind::UnexpectedEndOfText => 1126,
+                    SyntaxErrorKind::UnexpectedToken => 1012,
+                    // tsc has no limit of its own, and runs out of stack instead.
+                    SyntaxErrorKind::TooDeeplyNested => 1012,
+                };
+                (code, format!("{}.", error))
+            }
+            ConfigError::InvalidValue { message, field, .. } => invalid_value(field, message),
+            ConfigError::ExtendsNotFound { specifier, .. } => {
+                (6053, format!("File '{}' not found.", specifier))
+            }
+            ConfigError::CouldNotFindFile { path, .. } => {
+                (5083, format!("Cannot read file '{}'.", path.display()))
+            }
+            ConfigError::CircularExtends(_) => (18000, format!("{}.", error)),
+            other => (5083, format!("{}.", other)),
+        };
+
+        Diagnostic {
+            code,
+            message,
+            location: error.location().cloned(),
+        }
+    }
+
+    /// Describes a [CommandLineError], which has no location.
+    pub fn from_command_line_error(error: &CommandLineError) -> Self {
+        let code = match error {
+            CommandLineError::UnknownOption(_) => 5023,
+            CommandLineError::MissingArgument(_) => 6044,
+            CommandLineError::InvalidValue { .. } => 6046,
+            CommandLineError::ConfigFileOnly(_) => 6064,
+            CommandLineError::ConfigFileOnlyBoolean(_) => 6230,
+            CommandLineError::ProjectWithFiles => 5042,
+            CommandLineError::BuildNotFirst => 6369,
+        };
+
+        Diagnostic {
+            code,
+            message: format!("{}.", error),
+            location: None,
+        }
+    }
+
+    /// Renders the diagnostic, with a snippet of the offending line(s) if it has a location.
+    ///
+    /// `source` is the text of the configuration file. If it is `None`, the file is read from the
+    /// location's path; if that fails, the snippet is left out.
+    pub fn render(&self, style: DiagnosticStyle, source: Option<&str>) -> String {
+        let read;
+        let source = match (source, &self.location) {
+            (Some(source), _) => Some(source),
+            (
+                None,
+                Some(Location {
+                    path: Some(path), ..
+                }),
+            ) => {
+                read = std::fs::read_to_string(path).ok();
+                read.as_deref()
+            }
+            _ => None,
+        };
+
+        let mut out = String::new();
+        let position = self.location.as_ref().map(Location::to_string);
+        match style {
+            DiagnosticStyle::Tsc => {
+                if let Some(position) = &position {
+                    write!(out, "{} - ", position).unwrap();
+                }
+                writeln!(out, "error TS{}: {}", self.code, self.message).unwrap();
+            }
+            DiagnosticStyle::Codespan => {
+                writeln!(out, "error[TS{}]: {}", self.code, self.message).unwrap();
+            }
+        }
+
+        // The location may not belong to `source`, so check that it can be sliced with.
+        let (location, source) = match (&self.location, source) {
+            (Some(location), Some(source))
+                if location.span.start <= location.span.end
+                    && location.span.end <= source.len()
+                    && source.is_char_boundary(location.span.start)
+                    && source.is_char_boundary(location.span.end) =>
+            {
+                (location, source)
+            }
+            _ => return out,
+        };
+
+        let lines = snippet_lines(source, location);
+        let gutter = lines
+            .last()
+            .map_or(0, |(number, _, _)| number.to_string().len());
+        match style {
+            DiagnosticStyle::Tsc => {
+                out.push('\n');
+                for (number, text, (start, len)) in &lines {
+                    writeln!(out, "{:>width$} {}", number, text, width = gutter).unwrap();
+                    writeln!(
+                        out,
+                        "{:width$} {}{}",
+                        "",
+                        " ".repeat(*start),
+                        "~".repeat(*len),
+                        width = gutter
+                    )
+                    .unwrap();
+                }
+            }
+            DiagnosticStyle::Codespan => {
+                writeln!(
+                    out,
+                    "{:width$}┌─ {}",
+                    "",
+                    position.unwrap(),
+                    width = gutter + 1
+                )
+                .unwrap();
+                writeln!(out, "{:width$}│", "", width = gutter + 1).unwrap();
+                for (number, text, (start, len)) in &lines {
+                    writeln!(out, "{:>width$} │ {}", number, text, width = gutter).unwrap();
+                    writeln!(
+                        out,
+                        "{:width$} │ {}{}",
+                        "",
+                        " ".repeat(*start),
+                        "^".repeat(*len),
+                        width = gutter
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        for path in location.extends_chain.iter().rev() {
+            writeln!(out, "  = extended from {}", path.display()).unwrap();
+        }
+        out
+    }
+}
+
+/// The code and message tsc reports an invalid value of `field` with, given the message of the
+/// deserialization error.
+fn invalid_value(field: &str, message: &str) -> (u32, String) {
+    let option = field.rsplit('.').next().unwrap_or_default();
+    let fallback = (5024, format!("{}.", message));
+    if option.is_empty() {
+        return fallback;
+    }
+
+    // From the `FromStr` implementations of strict option values, see `ParseOptionError`.
+    if let Some((_, values)) = message
+        .strip_prefix("unknown value ")
+        .and_then(|rest| rest.rsplit_once(", expected one of: "))
+    {
+        let values: Vec<String> = values.split(", ").map(|v| format!("'{}'", v)).collect();
+        return (
+            6046,
+            format!(
+                "Argument for '--{}' option must be: {}.",
+                option,
+                values.join(", ")
+            ),
+        );
+    }
+
+    let expected = match message
+        .strip_prefix("invalid type: ")
+        .and_then(|rest| rest.rsplit_once(", expected "))
+    {
+        Some((_, expected)) => expected,
+        None => return fallback,
+    };
+    let type_name = match expected {
+        "a boolean" => "boolean",
+        "a string" => "string",
+        "a sequence" => "Array",
+        "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "f32" | "f64" => "number",
+        expected if expected.starts_with("a map") || expected.starts_with("struct ") => "object",
+        _ => return fallback,
+    };
+    (
+        5024,
+        format!(
+            "Compiler option '{}' requires a value of type {}.",
+            option, type_name
+        ),
+    )
+}
+
+/// The lines `location` covers, as (line number, text, (underline start, underline length)) in
+/// characters.
+fn snippet_lines<'a>(
+    source: &'a str,
+    location: &Location,
+) -> Vec<(usize, &'a str, (usize, usize))> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for (index, line) in source.split('\n').enumerate() {
+        let end = offset + line.len();
+        let text = line.strip_suffix('\r').unwrap_or(line);
+        let overlaps = offset <= location.span.end && location.span.start <= end;
+        if overlaps {
+            let start = location.span.start.max(offset) - offset;
+            let stop = (location.span.end.min(offset + text.len()) - offset).max(start);
+            let column = text[..start].chars().count();
+            // Point at something, even for an empty span such as the end of the file.
+            let len = text[start..stop].chars().count().max(1);
+            lines.push((index + 1, text, (column, len)));
+        }
+        if end >= location.span.end {
+            break;
+        }
+        offset = end + 1;
+    }
+    lines
+}
+
+/// Reports compiler options tsc does not know about (TS5023), which [crate::TsConfig] keeps in
+/// [CompilerOptions::extra].
+///
+/// `path` is only used to fill in the locations of the diagnostics.
+pub fn unknown_compiler_options(source: &str, path: Option<&Path>) -> Vec<Diagnostic> {
+    let value = match jsonc::parse(source) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+    let options = match value.get("compilerOptions").and_then(|o| o.as_object()) {
+        Some(options) => options,
+        None => return Vec::new(),
+    };
+
+    options
+        .iter()
+        .filter(|(key, value)| is_unknown_option(key, value))
+        .map(|(key, _)| {
+            let span = jsonc::member_span(source, &["compilerOptions", key])
+                .and_then(|member| member.key)
+                .unwrap_or(0..0);
+            Diagnostic {
+                code: 5023,
+                message: format!("Unknown compiler option '{}'.", key),
+                location: Some(*Location::new(path, source, span, &[])),
+            }
+        })
+        .collect()
+}
+
+/// Whether an option ends up in [CompilerOptions::extra]. Each option is checked on its own, so
+/// that an invalid value for another option does not get in the way.
+fn is_unknown_option(key: &str, value: &Value) -> bool {
+    let mut option = Map::new();
+    option.insert(key.to_string(), value.clone());
+    serde_json::from_value::<CompilerOptions>(Value::Object(option))
+        .is_ok_and(|options| options.extra.contains_key(key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TsConfig;
+
+    #[test]
+    fn renders_tsc_style() {
+        let source = "{\n  \"compilerOptions\": {\n    \"strict\": \"yes\"\n  }\n}";
+        let err = TsConfig::parse_str(source).unwrap_err();
+        let diagnostic = Diagnostic::from_error(&err);
+        assert_eq!(diagnostic.code, 5024);
+
+        let rendered = diagnostic.render(DiagnosticStyle::Tsc, Some(source));
+        assert!(rendered.starts_with(
+            "3:15 - error TS5024: Compiler option 'strict' requires a value of type boolean.\n"
+        ));
+        assert!(rendered.ends_with("\n\n3     \"strict\": \"yes\"\n                ~~~~~\n"));
+    }
+
+    #[test]
+    fn renders_codespan_style_with_extends_chain() {
+        let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/errors");
+        let path = dir.join("tsconfig.syntax.json");
+        let err = TsConfig::parse_file(&path).unwrap_err();
+
+        let rendered = Diagnostic::from_error(&err).render(DiagnosticStyle::Codespan, None);
+        let base = dir.join("./tsconfig.syntax_base.json");
+        assert_eq!(
+            rendered,
+            format!(
+                "error[TS1005]: ',' expected.\n  ┌─ {}:4:9\n  │\n4 │         \"noEmit\": true\n  │         ^^^^^^^^\n  = extended from {}\n",
+                base.display(),
+                path.canonicalize().unwrap().display()
+            )
+        );
+    }
+
+    #[test]
+    fn renders_without_location() {
+        let err = ConfigError::CircularExtends(vec!["a.json".into(), "a.json".into()]);
+        assert_eq!(
+            Diagnostic::from_error(&err).render(DiagnosticStyle::Tsc, None),
+            "error TS18000: Circularity detected while resolving configuration: a.json -> a.json.\n"
+        );
+    }
+
+    #[test]
+    fn words_invalid_values_as_tsc() {
+        let message =
+            |json: &str| Diagnostic::from_error(&TsConfig::parse_str(json).unwrap_err()).message;
+
+        assert_eq!(
+            message(r#"{"compilerOptions": {"jsx": "vue"}}"#),
+            "Argument for '--jsx' option must be: 'react', 'react-jsx', 'react-jsxdev', 'react-native', 'preserve'."
+        );
+        assert_eq!(
+            message(r#"{"compilerOptions": {"maxNodeModuleJsDepth": "deep"}}"#),
+            "Compiler option 'maxNodeModuleJsDepth' requires a value of type number."
+        );
+        assert_eq!(
+            message(r#"{"include": "src"}"#),
+            "Compiler option 'include' requires a value of type Array."
+        );
+    }
+
+    #[test]
+    fn skips_snippets_that_do_not_fit_the_source() {
+        let source = "{\"compilerOptions\": {\"strict\": \"oui\"}}";
+        let diagnostic = Diagnostic::from_error(&TsConfig::parse_str(source).unwrap_err());
+
+        let other = "{\"é\": \"ééééééééééééééééééééééééééééééé\"}";
+        assert!(!other.is_char_boundary(diagnostic.location.as_ref().unwrap().span.start));
+        assert_eq!(
+            diagnostic.render(DiagnosticStyle::Tsc, Some(other)),
+            "1:32 - error TS5024: Compiler option 'strict' requires a value of type boolean.\n"
+        );
+    }
+
+    #[test]
+    fn renders_command_line_errors() {
+        let err = crate::CommandLine::parse(["--strictt"]).unwrap_err();
+        assert_eq!(
+            Diagnostic::from_command_line_error(&err).render(DiagnosticStyle::Tsc, None),
+            "error TS5023: Unknown compiler option '--strictt'.\n"
+        );
+    }
+
+    #[test]
+    fn renders_multiline_spans() {
+        let source = "{\"files\": [\n  1\n]}";
+        let err = TsConfig::parse_str(source).unwrap_err();
+        let rendered = Diagnostic::from_error(&err).render(DiagnosticStyle::Tsc, Some(source));
+        assert!(rendered.ends_with("\n1 {\"files\": [\n            ~\n2   1\n  ~~~\n3 ]}\n  ~\n"));
+    }
+
+    #[test]
+    fn reports_unknown_compiler_options() {
+        let source =
+            "{\n  \"compilerOptions\": {\n    \"strict\": true,\n    \"strictt\": true\n  }\n}";
+        let diagnostics = unknown_compiler_options(source, Some(Path::new("tsconfig.json")));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].render(DiagnosticStyle::Tsc, Some(source)),
+            "tsconfig.json:4:5 - error TS5023: Unknown compiler option 'strictt'.\n\n4     \"strictt\": true\n      ~~~~~~~~~\n"
+        );
+    }
+
+    #[test]
+    fn knows_renamed_compiler_options() {
+        assert!(!is_unknown_option("emitBOM", &Value::Bool(true)));
+        assert!(!is_unknown_option("target", &Value::from("ES2015")));
+        // A known option with an invalid value is not unknown.
+        assert!(!is_unknown_option("strict", &Value::from("yes")));
+        assert!(is_unknown_option("emitBom", &Value::Bool(true)));
+    }
+}
@@ -0,0 +1,790 @@
+//! Editing of JSONC configuration files that keeps their comments and layout.
+//!
+//! A [Document] holds the original text of a file. Edits splice new text into it, touching only
+//! the entries they change and following the indentation, line endings and comma style already in
+//! use, so the result differs from the original by as little as possible.
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::jsonc::{self, Entry, Lexer, Node, NodeKind, SyntaxError, TokenKind};
+
+/// Errors when editing a [Document].
+/// This is non-exhaustive, and may be extended in the future.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EditError {
+    /// There is no value at the path, or no object or array to add it to.
+    #[error("No value at '{0}'")]
+    NotFound(String),
+    /// The path goes through a value that is neither an object nor an array.
+    #[error("'{0}' is not an object or an array")]
+    NotAContainer(String),
+    /// An array index was not a number, or was past the end of the array.
+    #[error("'{0}' is not a valid index")]
+    InvalidIndex(String),
+    /// [Document::insert] was given the path of an existing object member.
+    #[error("'{0}' already exists")]
+    AlreadyExists(String),
+    /// The value would nest objects and arrays deeper than a document can hold.
+    #[error("'{0}' would be nested too deeply")]
+    TooDeeplyNested(String),
+}
+
+/// A JSONC document that can be edited without losing its comments or formatting.
+///
+/// Paths are given as a list of segments, each being an object key or an array index, e.g.
+/// `&["compilerOptions", "lib", "0"]`.
+///
+/// ## Example
+/// ```
+/// use tsconfig::Document;
+/// use serde_json::json;
+///
+/// let mut doc = Document::parse(r#"{
+///     "compilerOptions": {
+///         // Catch more bugs.
+///         "strict": false
+///     }
+/// }"#).unwrap();
+///
+/// doc.set(&["compilerOptions", "strict"], &json!(true)).unwrap();
+/// doc.set(&["compilerOptions", "paths", "@/*"], &json!(["./src/*"])).unwrap();
+///
+/// assert_eq!(doc.to_string(), r#"{
+///     "compilerOptions": {
+///         // Catch more bugs.
+///         "strict": true,
+///         "paths": {
+///             "@/*": ["./src/*"]
+///         }
+///     }
+/// }"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Document {
+    text: String,
+    root: Node,
+}
+
+/// A replacement of a byte range of the text.
+type Edit = (Range<usize>, String);
+
+impl Document {
+    /// Parses a JSONC document. Comments and trailing commas are allowed, as in [crate::parse_to_value].
+    pub fn parse(text: &str) -> Result<Self, SyntaxError> {
+        Ok(Document {
+            root: jsonc::parse_tree(text)?,
+            text: text.to_string(),
+        })
+    }
+
+    /// The current text of the document.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Converts the document into its current text.
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// The current contents of the document, without comments.
+    pub fn to_value(&self) -> Value {
+        // The text always parses as a tree, see [Document::apply], and so as a value too.
+        jsonc::parse(&self.text).expect("documents are always valid")
+    }
+
+    /// Returns the value at `path`, if there is one.
+    pub fn get(&self, path: &[&str]) -> Option<Value> {
+        let node = self.find(path)?;
+        Some(jsonc::parse(&self.text[node.span.clone()]).expect("documents are always valid"))
+    }
+
+    /// Sets the value at `path`, replacing the existing value if there is one.
+    ///
+    /// Missing object members are added at the end of their object, along with any missing
+    /// objects leading to them. An array index may be one past the end of the array, to append.
+    pub fn set(&mut self, path: &[&str], value: &Value) -> Result<(), EditError> {
+        let edits = self.plan_set(path, value)?;
+        self.apply(path, edits)
+    }
+
+    /// Inserts a value at `path` without replacing anything.
+    ///
+    /// If the parent is an array, the value is inserted before the given index, which may also be
+    /// one past the end of the array. If it is an object, the member is added at its end, and
+    /// must not exist yet.
+    pub fn insert(&mut self, path: &[&str], value: &Value) -> Result<(), EditError> {
+        let edits = self.plan_insert(path, value)?;
+        self.apply(path, edits)
+    }
+
+    /// Removes the object member or array element at `path`, returning its value.
+    ///
+    /// Comments on the same line as the entry are removed with it; comments on lines of their
+    /// own are kept.
+    pub fn remove(&mut self, path: &[&str]) -> Result<Value, EditError> {
+        let removed = self
+            .get(path)
+            .ok_or_else(|| EditError::NotFound(display_path(path)))?;
+        let edits = self.plan_remove(path)?;
+        self.apply(path, edits)?;
+        Ok(removed)
+    }
+
+    fn find(&self, path: &[&str]) -> Option<&Node> {
+        let mut node = &self.root;
+        for segment in path {
+            node = &lookup(node, segment)?.1.value;
+        }
+        Some(node)
+    }
+
+    fn plan_set(&self, path: &[&str], value: &Value) -> Result<Vec<Edit>, EditError> {
+        if self.root.kind == NodeKind::Empty {
+            // Write the whole document after its comments, on a line of its own.
+            let at = self.root.span.clone();
+            let separator = if self.text.is_empty() || self.text.ends_with('\n') {
+                ""
+            } else {
+                self.newline()
+            };
+            let document = self.render(&nest(path, value.clone()), Some(""));
+            return Ok(vec![(at, format!("{}{}", separator, document))]);
+        }
+        let (last, parents) = match path.split_last() {
+            Some(split) => split,
+            None => return Ok(vec![(self.root.span.clone(), self.render(value, Some("")))]),
+        };
+
+        let mut node = &self.root;
+        for (depth, segment) in parents.iter().enumerate() {
+            match lookup(node, segment) {
+                Some((_, entry)) => node = &entry.value,
+                None => {
+                    let nested = nest(&path[depth + 1..], value.clone());
+                    return self.plan_append(node, segment, &nested, &path[..depth]);
+                }
+            }
+        }
+
+        match lookup(node, last) {
+            Some((_, entry)) => {
+                let indent = self.entry_indent(node, entry);
+                let span = entry.value.span.clone();
+                Ok(vec![(span, self.render(value, indent.as_deref()))])
+            }
+            None => self.plan_append(node, last, value, parents),
+        }
+    }
+
+    fn plan_insert(&self, path: &[&str], value: &Value) -> Result<Vec<Edit>, EditError> {
+        let (last, parents) = path
+            .split_last()
+            .ok_or_else(|| EditError::AlreadyExists(display_path(path)))?;
+        let parent = self
+            .find(parents)
+            .ok_or_else(|| EditError::NotFound(display_path(parents)))?;
+
+        match &parent.kind {
+            NodeKind::Array(items) => match last.parse::<usize>() {
+                Ok(index) if index <= items.len() => {
+                    Ok(self.plan_insert_entry(parent, items, index, None, value))
+                }
+                _ => Err(EditError::InvalidIndex(display_path(path))),
+            },
+            NodeKind::Object(_) if lookup(parent, last).is_some() => {
+                Err(EditError::AlreadyExists(display_path(path)))
+            }
+            _ => self.plan_append(parent, last, value, parents),
+        }
+    }
+
+    /// Adds a member or element to the end of `container`, whose path is `container_path`.
+    fn plan_append(
+        &self,
+        container: &Node,
+        segment: &str,
+        value: &Value,
+        container_path: &[&str],
+    ) -> Result<Vec<Edit>, EditError> {
+        match &container.kind {
+            NodeKind::Object(members) => {
+                Ok(self.plan_insert_entry(container, members, members.len(), Some(segment), value))
+            }
+            NodeKind::Array(items) if segment.parse() == Ok(items.len()) => {
+                Ok(self.plan_insert_entry(container, items, items.len(), None, value))
+            }
+            NodeKind::Array(_) => {
+                let mut path = container_path.to_vec();
+                path.push(segment);
+                Err(EditError::InvalidIndex(display_path(&path)))
+            }
+            NodeKind::Scalar => Err(EditError::NotAContainer(display_path(container_path))),
+            NodeKind::Empty => self.plan_set(&[segment], value),
+        }
+    }
+
+    fn plan_remove(&self, path: &[&str]) -> Result<Vec<Edit>, EditError> {
+        let not_found = || EditError::NotFound(display_path(path));
+        let (last, parents) = path.split_last().ok_or_else(not_found)?;
+        let container = self.find(parents).ok_or_else(not_found)?;
+        let (index, _) = lookup(container, last).ok_or_else(not_found)?;
+        let entries = match &container.kind {
+            NodeKind::Object(entries) | NodeKind::Array(entries) => entries,
+            NodeKind::Scalar | NodeKind::Empty => return Err(not_found()),
+        };
+
+        let entry = &entries[index];
+        let inner = container.span.start + 1..container.span.end - 1;
+        let end = self.trailing_comments_end(entry.end());
+
+        if entries.len() == 1 {
+            let is_blank = |range: Range<usize>| self.text[range].trim().is_empty();
+            if is_blank(inner.start..entry.start()) && is_blank(end..inner.end) {
+                return Ok(vec![(inner, String::new())]);
+            }
+            return Ok(vec![(entry.start()..end, String::new())]);
+        }
+
+        let is_last = index == entries.len() - 1;
+        let next_on_later_line = entries.get(index + 1).map_or(true, |next| {
+            self.text[entry.end()..next.start()].contains('\n')
+        });
+        match self.own_line_start(entry.start()) {
+            Some(line_start) if next_on_later_line => {
+                // Take the rest of the line, including its line break.
+                let rest = &self.text[end..];
+                let mut end = end + rest.len() - rest.trim_start_matches([' ', '\t']).len();
+                if self.text[end..].starts_with("\r\n") {
+                    end += 2;
+                } else if self.text[end..].starts_with('\n') {
+                    end += 1;
+                }
+                let mut edits = vec![(line_start..end, String::new())];
+                if is_last && entry.comma.is_none() {
+                    if let Some(comma) = &entries[index - 1].comma {
+                        edits.push((comma.clone(), String::new()));
+                    }
+                }
+                Ok(edits)
+            }
+            _ if !is_last => Ok(vec![(
+                entry.start()..entries[index + 1].start(),
+                String::new(),
+            )]),
+            _ => {
+                let previous = &entries[index - 1];
+                Ok(vec![(
+                    previous.value.span.end..entry.value.span.end,
+                    String::new(),
+                )])
+            }
+        }
+    }
+
+    /// Inserts an entry into `container` before `index`, following the layout of its other entries.
+    fn plan_insert_entry(
+        &self,
+        container: &Node,
+        entries: &[Entry],
+        index: usize,
+        key: Option<&str>,
+        value: &Value,
+    ) -> Vec<Edit> {
+        let newline = self.newline();
+        let entry_text = |indent: Option<&str>| {
+            let value = self.render(value, indent);
+            match key {
+                Some(key) => format!("{}: {}", Value::from(key), value),
+                None => value,
+            }
+        };
+        let open_end = container.span.start + 1;
+        let close_start = container.span.end - 1;
+
+        if entries.is_empty() {
+            let inner = &self.text[open_end..close_start];
+            let multiline = inner.contains('\n')
+                || (matches!(container.kind, NodeKind::Object(_)) && self.text.contains('\n'));
+            if !multiline {
+                return vec![(open_end..close_start, entry_text(None))];
+            }
+            let outer = self.line_indent(container.span.start);
+            let indent = format!("{}{}", outer, self.indent_unit());
+            let text = format!("{}{}{}", newline, indent, entry_text(Some(&indent)));
+            if inner.trim().is_empty() {
+                return vec![(
+                    open_end..close_start,
+                    format!("{}{}{}", text, newline, outer),
+                )];
+            }
+            return vec![(open_end..open_end, text)];
+        }
+
+        let multiline = self.text[open_end..entries[0].start()].contains('\n');
+        if !multiline {
+            return match entries.get(index) {
+                Some(next) => vec![(
+                    next.start()..next.start(),
+                    format!("{}, ", entry_text(None)),
+                )],
+                None => {
+                    let last = &entries[entries.len() - 1];
+                    match last.comma {
+                        Some(_) => {
+                            vec![(last.end()..last.end(), format!(" {},", entry_text(None)))]
+                        }
+                        None => vec![(last.end()..last.end(), format!(", {}", entry_text(None)))],
+                    }
+                }
+            };
+        }
+
+        match entries.get(index) {
+            Some(next) => {
+                let indent = self.line_indent(next.start());
+                let text = format!("{},", entry_text(Some(&indent)));
+                match self.own_line_start(next.start()) {
+                    Some(line_start) => vec![(
+                        line_start..line_start,
+                        format!("{}{}{}", indent, text, newline),
+                    )],
+                    None => vec![(next.start()..next.start(), format!("{} ", text))],
+                }
+            }
+            None => {
+                let last = &entries[entries.len() - 1];
+                let indent = self.line_indent(last.start());
+                let at = self.trailing_comments_end(last.end());
+                let text = entry_text(Some(&indent));
+                match last.comma {
+                    Some(_) => vec![(at..at, format!("{}{}{},", newline, indent, text))],
+                    None => {
+                        // Add the missing comma right after the value, before any comment.
+                        let value_end = last.value.span.end;
+                        vec![(
+                            value_end..at,
+                            format!(
+                                ",{}{}{}{}",
+                                &self.text[value_end..at],
+                                newline,
+                                indent,
+                                text
+                            ),
+                        )]
+                    }
+                }
+            }
+        }
+    }
+
+    /// The indentation to render an entry's value with, or `None` to render it on one line.
+    fn entry_indent(&self, container: &Node, entry: &Entry) -> Option<String> {
+        let open_end = container.span.start + 1;
+        if self.text[open_end..entry.start()].contains('\n')
+            || self.own_line_start(entry.start()).is_some()
+        {
+            Some(self.line_indent(entry.start()))
+        } else {
+            None
+        }
+    }
+
+    /// Applies the edits for the value at `path`, leaving the document untouched if they fail.
+    fn apply(&mut self, path: &[&str], mut edits: Vec<Edit>) -> Result<(), EditError> {
+        edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+        let mut text = self.text.clone();
+        for (range, edit) in edits {
+            text.replace_range(range, &edit);
+        }
+        // Edits only splice in well-formed JSON, so the only way they can break the document is
+        // by nesting it deeper than the parser allows.
+        self.root =
+            jsonc::parse_tree(&text).map_err(|_| EditError::TooDeeplyNested(display_path(path)))?;
+        self.text = text;
+        Ok(())
+    }
+
+    /// Renders a value, on several lines indented from `indent`, or on one line if it is `None`.
+    ///
+    /// Arrays are kept on one line unless they contain objects or arrays, as is usual in
+    /// configuration files.
+    fn render(&self, value: &Value, indent: Option<&str>) -> String {
+        let newline = self.newline();
+        match value {
+            Value::Object(object) if !object.is_empty() => match indent {
+                Some(indent) => {
+                    let inner = format!("{}{}", indent, self.indent_unit());
+                    let members: Vec<String> = object
+                        .iter()
+                        .map(|(key, value)| {
+                            format!(
+                                "{}{}{}: {}",
+                                newline,
+                                inner,
+                                Value::from(key.as_str()),
+                                self.render(value, Some(&inner))
+                            )
+                        })
+                        .collect();
+                    format!("{{{}{}{}}}", members.join(","), newline, indent)
+                }
+                None => {
+                    let members: Vec<String> = object
+                        .iter()
+                        .map(|(key, value)| {
+                            format!(
+                                "{}: {}",
+                                Value::from(key.as_str()),
+                                self.render(value, None)
+                            )
+                        })
+                        .collect();
+                    format!("{{{}}}", members.join(", "))
+                }
+            },
+            Value::Array(items) if !items.is_empty() => {
+                let nested = items.iter().any(|item| item.is_object() || item.is_array());
+                match indent {
+                    Some(indent) if nested => {
+                        let inner = format!("{}{}", indent, self.indent_unit());
+                        let items: Vec<String> = items
+                            .iter()
+                            .map(|item| {
+                                format!("{}{}{}", newline, inner, self.render(item, Some(&inner)))
+                            })
+                            .collect();
+                        format!("[{}{}{}]", items.join(","), newline, indent)
+                    }
+                    _ => {
+                        let items: Vec<String> =
+                            items.iter().map(|item| self.render(item, None)).collect();
+                        format!("[{}]", items.join(", "))
+                    }
+                }
+            }
+            other => other.to_string(),
+        }
+    }
+
+    fn newline(&self) -> &'static str {
+        if self.text.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        }
+    }
+
+    /// The indentation of the first indented line, or two spaces if there is none, as `tsc --init` uses.
+    fn indent_unit(&self) -> String {
+        self.text
+            .lines()
+            .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+            .find(|indent| !indent.is_empty())
+            .unwrap_or("  ")
+            .to_string()
+    }
+
+    fn line_start(&self, pos: usize) -> usize {
+        self.text[..pos].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    /// The start of the line containing `pos`, if only whitespace precedes `pos` on it.
+    fn own_line_start(&self, pos: usize) -> Option<usize> {
+        let line_start = self.line_start(pos);
+        self.text[line_start..pos]
+            .trim()
+            .is_empty()
+            .then_some(line_start)
+    }
+
+    fn line_indent(&self, pos: usize) -> String {
+        let line = &self.text[self.line_start(pos)..];
+        line[..line.len() - line.trim_start_matches([' ', '\t']).len()].to_string()
+    }
+
+    /// The end of the comments following `pos` on the same line, or `pos` if there are none.
+    fn trailing_comments_end(&self, pos: usize) -> usize {
+        let mut end = pos;
+        let mut lexer = Lexer::new(&self.text[pos..]);
+        while let Ok(token) = lexer.next_token() {
+            match token.kind {
+                TokenKind::Whitespace
+                    if !self.text[pos + token.span.start..pos + token.span.end].contains('\n') => {}
+                TokenKind::LineComment | TokenKind::BlockComment => end = pos + token.span.end,
+                _ => break,
+            }
+        }
+        end
+    }
+}
+
+impl FromStr for Document {
+    type Err = SyntaxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Document::parse(s)
+    }
+}
+
+/// Writes the current text of the document.
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Finds the entry of `node` at `segment`. For duplicate keys this is the last one, which is the
+/// one that takes effect.
+fn lookup<'n>(node: &'n Node, segment: &str) -> Option<(usize, &'n Entry)> {
+    match &node.kind {
+        NodeKind::Object(members) => members
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, member)| member.key.as_ref().is_some_and(|(key, _)| key == segment)),
+        NodeKind::Array(items) => {
+            let index = segment.parse().ok()?;
+            Some((index, items.get(index)?))
+        }
+        NodeKind::Scalar | NodeKind::Empty => None,
+    }
+}
+
+/// `value` nested in objects along `path`.
+fn nest(path: &[&str], value: Value) -> Value {
+    path.iter().rev().fold(value, |value, key| {
+        let mut object = serde_json::Map::new();
+        object.insert(key.to_string(), value);
+        Value::Object(object)
+    })
+}
+
+fn display_path(path: &[&str]) -> String {
+    path.join(".")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    const CONFIG: &str = r#"{
+  // Shared settings.
+  "extends": "./tsconfig.base.json",
+  "compilerOptions": {
+    "target": "es2017", /* Set the JavaScript language version. */
+    "strict": false, // TODO: enable
+    "lib": ["dom", "es2017"]
+  },
+  "include": ["src"]
+}
+"#;
+
+    fn edited(edit: impl FnOnce(&mut Document)) -> String {
+        let mut doc = Document::parse(CONFIG).unwrap();
+        edit(&mut doc);
+        doc.into_string()
+    }
+
+    #[test]
+    fn round_trips_unedited() {
+        assert_eq!(Document::parse(CONFIG).unwrap().to_string(), CONFIG);
+        assert_eq!(
+            Document::parse(CONFIG)
+                .unwrap()
+                .get(&["compilerOptions", "lib", "1"]),
+            Some(json!("es2017"))
+        );
+    }
+
+    #[test]
+    fn replaces_values_in_place() {
+        let text = edited(|doc| {
+            doc.set(&["compilerOptions", "strict"], &json!(true))
+                .unwrap()
+        });
+        assert_eq!(
+            text,
+            CONFIG.replace("\"strict\": false", "\"strict\": true")
+        );
+    }
+
+    #[test]
+    fn appends_members_after_trailing_comments() {
+        let text = edited(|doc| {
+            doc.set(&["compilerOptions", "outDir"], &json!("dist"))
+                .unwrap()
+        });
+        assert_eq!(
+            text,
+            CONFIG.replace(
+                "\"lib\": [\"dom\", \"es2017\"]\n",
+                "\"lib\": [\"dom\", \"es2017\"],\n    \"outDir\": \"dist\"\n"
+            )
+        );
+
+        let text = edited(|doc| doc.set(&["exclude"], &json!(["dist"])).unwrap());
+        assert!(text.ends_with("  \"include\": [\"src\"],\n  \"exclude\": [\"dist\"]\n}\n"));
+    }
+
+    #[test]
+    fn creates_missing_objects() {
+        let text = edited(|doc| {
+            doc.set(&["compilerOptions", "paths", "@/*"], &json!(["./src/*"]))
+                .unwrap()
+        });
+        assert!(text.contains(
+            "\"lib\": [\"dom\", \"es2017\"],\n    \"paths\": {\n      \"@/*\": [\"./src/*\"]\n    }\n  },"
+        ));
+    }
+
+    #[test]
+    fn removes_members_with_their_comments() {
+        let text = edited(|doc| {
+            assert_eq!(
+                doc.remove(&["compilerOptions", "strict"]).unwrap(),
+                json!(false)
+            );
+        });
+        assert_eq!(
+            text,
+            CONFIG.replace("    \"strict\": false, // TODO: enable\n", "")
+        );
+
+        let text = edited(|doc| {
+            doc.remove(&["include"]).unwrap();
+        });
+        assert!(text.ends_with("\"lib\": [\"dom\", \"es2017\"]\n  }\n}\n"));
+
+        let text = edited(|doc| {
+            doc.remove(&["extends"]).unwrap();
+        });
+        assert!(text.starts_with("{\n  // Shared settings.\n  \"compilerOptions\": {"));
+    }
+
+    #[test]
+    fn edits_arrays() {
+        let text = edited(|doc| {
+            doc.insert(&["compilerOptions", "lib", "1"], &json!("dom.iterable"))
+                .unwrap();
+            doc.insert(&["include", "1"], &json!("test")).unwrap();
+            doc.remove(&["compilerOptions", "lib", "0"]).unwrap();
+        });
+        assert!(text.contains("\"lib\": [\"dom.iterable\", \"es2017\"]\n"));
+        assert!(text.contains("\"include\": [\"src\", \"test\"]\n"));
+
+        let text = edited(|doc| {
+            doc.remove(&["compilerOptions", "lib", "1"]).unwrap();
+            doc.set(&["include", "0"], &json!("lib")).unwrap();
+        });
+        assert!(text.contains("\"lib\": [\"dom\"]\n"));
+        assert!(text.contains("\"include\": [\"lib\"]\n"));
+    }
+
+    #[test]
+    fn keeps_trailing_comma_style() {
+        let mut doc = Document::parse("{\n    \"a\": 1,\n    \"b\": [1,],\n}").unwrap();
+        doc.set(&["c"], &json!(true)).unwrap();
+        doc.set(&["b", "1"], &json!(2)).unwrap();
+        assert_eq!(
+            doc.as_str(),
+            "{\n    \"a\": 1,\n    \"b\": [1, 2,],\n    \"c\": true,\n}"
+        );
+
+        doc.remove(&["c"]).unwrap();
+        doc.remove(&["a"]).unwrap();
+        assert_eq!(doc.as_str(), "{\n    \"b\": [1, 2,],\n}");
+    }
+
+    #[test]
+    fn fills_empty_containers() {
+        let mut doc = Document::parse("{}").unwrap();
+        doc.set(&["compilerOptions", "strict"], &json!(true))
+            .unwrap();
+        assert_eq!(doc.as_str(), "{\"compilerOptions\": {\"strict\": true}}");
+
+        let mut doc = Document::parse("{\r\n\t\"compilerOptions\": {}\r\n}").unwrap();
+        doc.set(&["compilerOptions", "types"], &json!(["node"]))
+            .unwrap();
+        assert_eq!(
+            doc.as_str(),
+            "{\r\n\t\"compilerOptions\": {\r\n\t\t\"types\": [\"node\"]\r\n\t}\r\n}"
+        );
+
+        doc.remove(&["compilerOptions", "types"]).unwrap();
+        assert_eq!(doc.as_str(), "{\r\n\t\"compilerOptions\": {}\r\n}");
+    }
+
+    #[test]
+    fn reports_invalid_edits() {
+        let mut doc = Document::parse(CONFIG).unwrap();
+
+        assert_eq!(
+            doc.set(&["extends", "x"], &json!(1)),
+            Err(EditError::NotAContainer("extends".to_string()))
+        );
+        assert_eq!(
+            doc.set(&["include", "5"], &json!("x")),
+            Err(EditError::InvalidIndex("include.5".to_string()))
+        );
+        assert_eq!(
+            doc.insert(&["extends"], &json!("x")),
+            Err(EditError::AlreadyExists("extends".to_string()))
+        );
+        assert_eq!(
+            doc.remove(&["compilerOptions", "noEmit"]),
+            Err(EditError::NotFound("compilerOptions.noEmit".to_string()))
+        );
+        assert_eq!(doc.as_str(), CONFIG);
+    }
+
+    #[test]
+    fn edits_documents_without_value() {
+        let mut doc = Document::parse("").unwrap();
+        assert_eq!(doc.to_value(), json!({}));
+        assert_eq!(doc.get(&[]), Some(json!({})));
+        assert_eq!(doc.get(&["compilerOptions"]), None);
+        assert_eq!(
+            doc.remove(&["compilerOptions"]),
+            Err(EditError::NotFound("compilerOptions".to_string()))
+        );
+
+        doc.set(&["compilerOptions", "strict"], &json!(true))
+            .unwrap();
+        assert_eq!(
+            doc.as_str(),
+            "{\n  \"compilerOptions\": {\n    \"strict\": true\n  }\n}"
+        );
+
+        let mut doc = Document::parse("// Nothing yet.").unwrap();
+        doc.insert(&["files"], &json!([])).unwrap();
+        assert_eq!(doc.as_str(), "// Nothing yet.\n{\n  \"files\": []\n}");
+    }
+
+    #[test]
+    fn rejects_values_nested_too_deeply() {
+        let mut doc = Document::parse("{}").unwrap();
+        let deep = (0..200).fold(json!([]), |value, _| json!([value]));
+
+        assert_eq!(
+            doc.set(&["a"], &deep),
+            Err(EditError::TooDeeplyNested("a".to_string()))
+        );
+        assert_eq!(
+            doc.insert(&["a"], &deep),
+            Err(EditError::TooDeeplyNested("a".to_string()))
+        );
+        assert_eq!(doc.as_str(), "{}");
+        assert_eq!(doc.to_value(), json!({}));
+
+        doc.set(&["a"], &json!([[1]])).unwrap();
+        assert_eq!(doc.get(&["a"]), Some(json!([[1]])));
+    }
+}
@@ -0,0 +1,467 @@
+//! The compiler options and type acquisition settings tsc actually uses, with its defaults filled
+//! in.
+
+use std::path::Path;
+
+use crate::{
+    CompilerOptions, Lib, Module, ModuleDetection, ModuleResolutionMode, Target, TsConfig,
+    TsVersion, TypeAcquisition,
+};
+
+/// The effective value of the compiler options that tsc defaults, many of them from other
+/// options, computed from the options written in a configuration file.
+///
+/// Defaults are those of [TsVersion::LATEST] unless computed with
+/// [CompilerOptions::effective_for].
+///
+/// ## Example
+/// ```
+/// use tsconfig::{Module, TsConfig};
+///
+/// let config = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true, "target": "ES2020"}}"#).unwrap();
+/// let options = config.compiler_options.unwrap().effective();
+///
+/// assert!(options.strict_null_checks);
+/// assert_eq!(options.module, Module::Es2015);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveCompilerOptions {
+    /// Defaults to `ES2022` for the `node16` and `node18` modules and to `ESNext` for `nodenext`,
+    /// and otherwise to `ES5`, or `ES3` before TypeScript 5.0.
+    pub target: Target,
+    /// Defaults to `ES2015` for targets from `ES2015` on, and to `CommonJS` otherwise.
+    pub module: Module,
+    /// Defaults from `module`, see [Module::implied_module_resolution], except that `CommonJS`
+    /// implies `node` before TypeScript 5.0.
+    pub module_resolution: ModuleResolutionMode,
+    /// Defaults from `target`, and is empty with `noLib`.
+    pub lib: Vec<Lib>,
+    pub strict: bool,
+    /// Defaults to `strict`.
+    pub no_implicit_any: bool,
+    /// Defaults to `strict`.
+    pub no_implicit_this: bool,
+    /// Defaults to `strict`.
+    pub strict_null_checks: bool,
+    /// Defaults to `strict`.
+    pub strict_function_types: bool,
+    /// Defaults to `strict`.
+    pub strict_bind_call_apply: bool,
+    /// Defaults to `strict`.
+    pub strict_property_initialization: bool,
+    /// Defaults to `strict`.
+    pub always_strict: bool,
+    /// Defaults to `strict`, since TypeScript 4.4.
+    pub use_unknown_in_catch_variables: bool,
+    /// Defaults to `strict`, since TypeScript 5.6.
+    pub strict_builtin_iterator_return: bool,
+    /// Defaults to `true` for the `node16`, `node18`, `nodenext` and `preserve` modules.
+    pub es_module_interop: bool,
+    /// Defaults to `true` with `esModuleInterop`, the `system` module or `bundler` resolution.
+    pub allow_synthetic_default_imports: bool,
+    pub composite: bool,
+    /// Always on with `composite`.
+    pub declaration: bool,
+    /// Always on with `composite`.
+    pub incremental: bool,
+    /// Defaults to `checkJs`.
+    pub allow_js: bool,
+    pub check_js: bool,
+    /// Always on with `verbatimModuleSyntax`.
+    pub isolated_modules: bool,
+    /// Always on with `isolatedModules`.
+    pub preserve_const_enums: bool,
+    /// Defaults to `true` for targets from `ES2022` on, since TypeScript 4.3.
+    pub use_define_for_class_fields: bool,
+    /// Defaults to `force` for the `node16`, `node18` and `nodenext` modules, and to `auto`
+    /// otherwise.
+    pub module_detection: ModuleDetection,
+    /// Defaults to `true` for `node16`, `nodenext` and `bundler` resolution.
+    pub resolve_package_json_exports: bool,
+    /// Defaults to `true` for `node16`, `nodenext` and `bundler` resolution.
+    pub resolve_package_json_imports: bool,
+}
+
+impl CompilerOptions {
+    /// Computes the options tsc uses given these, see [EffectiveCompilerOptions].
+    pub fn effective(&self) -> EffectiveCompilerOptions {
+        self.effective_for(TsVersion::LATEST)
+    }
+
+    /// Computes the options a given version of tsc uses given these.
+    ///
+    /// Options that do not exist in that version are not reported here, see
+    /// [CompilerOptions::check_version].
+    pub fn effective_for(&self, version: TsVersion) -> EffectiveCompilerOptions {
+        EffectiveCompilerOptions::new(self, version)
+    }
+}
+
+impl From<&CompilerOptions> for EffectiveCompilerOptions {
+    fn from(options: &CompilerOptions) -> Self {
+        options.effective()
+    }
+}
+
+impl EffectiveCompilerOptions {
+    fn new(options: &CompilerOptions, version: TsVersion) -> Self {
+        let target = options.target.clone().unwrap_or(match options.module {
+            Some(Module::Node16) | Some(Module::Node18) => Target::Es2022,
+            Some(Module::NodeNext) => Target::EsNext,
+            _ if version < TsVersion::new(5, 0) => Target::Es3,
+            _ => Target::Es5,
+        });
+        let module = options
+            .module
+            .clone()
+            .unwrap_or(if target >= Target::Es2015 {
+                Module::Es2015
+            } else {
+                Module::CommonJs
+            });
+        let module_resolution = options
+            .module_resolution
+            .unwrap_or_else(|| implied_module_resolution(&module, version));
+
+        let lib = match (&options.lib, options.no_lib) {
+            (_, Some(true)) => Vec::new(),
+            (Some(lib), _) => lib.clone(),
+            (None, _) => target.default_lib(),
+        };
+
+        let strict = options.strict.unwrap_or(false);
+        let strict_option = |option: Option<bool>| option.unwrap_or(strict);
+        // Strict options added after `strict` only follow it from the version that added them.
+        let strict_option_since =
+            |option: Option<bool>, since: TsVersion| option.unwrap_or(strict && version >= since);
+
+        let es_module_interop = options
+            .es_module_interop
+            .unwrap_or(module.is_node() || module == Module::Preserve);
+        let allow_synthetic_default_imports = options.allow_synthetic_default_imports.unwrap_or(
+            es_module_interop
+                || module == Module::System
+                || module_resolution == ModuleResolutionMode::Bundler,
+        );
+
+        let composite = options.composite.unwrap_or(false);
+        let check_js = options.check_js.unwrap_or(false);
+        let isolated_modules = options.isolated_modules.unwrap_or(false)
+            || options.verbatim_module_syntax.unwrap_or(false);
+        let module_detection = options
+            .module_detection
+            .clone()
+            .unwrap_or(if module.is_node() {
+                ModuleDetection::Force
+            } else {
+                ModuleDetection::Auto
+            });
+        let resolves_package_json = matches!(
+            module_resolution,
+            ModuleResolutionMode::Node16
+                | ModuleResolutionMode::NodeNext
+                | ModuleResolutionMode::Bundler
+        );
+
+        EffectiveCompilerOptions {
+            use_define_for_class_fields: options
+                .use_define_for_class_fields
+                .unwrap_or(version >= TsVersion::new(4, 3) && target >= Target::Es2022),
+            target,
+            module,
+            module_resolution,
+            lib,
+            strict,
+            no_implicit_any: strict_option(options.no_implicit_any),
+            no_implicit_this: strict_option(options.no_implicit_this),
+            strict_null_checks: strict_option(options.strict_null_checks),
+            strict_function_types: strict_option(options.strict_function_types),
+            strict_bind_call_apply: strict_option(options.strict_bind_call_apply),
+            strict_property_initialization: strict_option(options.strict_property_initialization),
+            always_strict: strict_option(options.always_strict),
+            use_unknown_in_catch_variables: strict_option_since(
+                options.use_unknown_in_catch_variables,
+                TsVersion::new(4, 4),
+            ),
+            strict_builtin_iterator_return: strict_option_since(
+                options.strict_builtin_iterator_return,
+                TsVersion::new(5, 6),
+            ),
+            es_module_interop,
+            allow_synthetic_default_imports,
+            composite,
+            declaration: options.declaration.unwrap_or(false) || composite,
+            incremental: options.incremental.unwrap_or(false) || composite,
+            allow_js: options.allow_js.unwrap_or(check_js),
+            check_js,
+            isolated_modules,
+            preserve_const_enums: options.preserve_const_enums.unwrap_or(false) || isolated_modules,
+            module_detection,
+            resolve_package_json_exports: options
+                .resolve_package_json_exports
+                .unwrap_or(resolves_package_json),
+            resolve_package_json_imports: options
+                .resolve_package_json_imports
+                .unwrap_or(resolves_package_json),
+        }
+    }
+}
+
+/// The module resolution tsc uses when `moduleResolution` is not set.
+fn implied_module_resolution(module: &Module, version: TsVersion) -> ModuleResolutionMode {
+    match module {
+        // `node10` is the name `node` was given in TypeScript 5.0.
+        Module::CommonJs if version < TsVersion::new(5, 0) => ModuleResolutionMode::Node,
+        other => other.implied_module_resolution(),
+    }
+}
+
+/// The effective value of `typeAcquisition`, with tsc's defaults filled in.
+///
+/// ## Example
+/// ```
+/// use tsconfig::TsConfig;
+///
+/// let config = TsConfig::parse_str(r#"{"typeAcquisition": {"include": ["jest"]}}"#).unwrap();
+///
+/// assert!(config.effective_type_acquisition(&"jsconfig.json").enable);
+/// assert!(!config.effective_type_acquisition(&"tsconfig.json").enable);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EffectiveTypeAcquisition {
+    /// Defaults to `true` for jsconfig.json files, and to `false` otherwise.
+    pub enable: bool,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub disable_filename_based_type_acquisition: bool,
+}
+
+impl TsConfig {
+    /// Computes the type acquisition settings tsc uses for this configuration, read from `path`.
+    ///
+    /// Only the file name of `path` matters: type acquisition is enabled by default in
+    /// jsconfig.json files.
+    pub fn effective_type_acquisition<P: AsRef<Path>>(&self, path: &P) -> EffectiveTypeAcquisition {
+        let is_jsconfig = path
+            .as_ref()
+            .file_name()
+            .is_some_and(|name| name == "jsconfig.json");
+        let mut effective = EffectiveTypeAcquisition {
+            enable: is_jsconfig,
+            ..Default::default()
+        };
+
+        match &self.type_acquisition {
+            Some(TypeAcquisition::Bool(enable)) => effective.enable = *enable,
+            Some(TypeAcquisition::Object {
+                enable,
+                include,
+                exclude,
+                disable_filename_based_type_acquisition,
+            }) => {
+                effective.enable = enable.unwrap_or(is_jsconfig);
+                effective.include = include.clone().unwrap_or_default();
+                effective.exclude = exclude.clone().unwrap_or_default();
+                effective.disable_filename_based_type_acquisition =
+                    disable_filename_based_type_acquisition.unwrap_or(false);
+            }
+            None => {}
+        }
+        effective
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TsConfig;
+
+    fn effective(json: &str) -> EffectiveCompilerOptions {
+        TsConfig::parse_str(json)
+            .unwrap()
+            .compiler_options
+            .unwrap_or_default()
+            .effective()
+    }
+
+    #[test]
+    fn defaults_without_options() {
+        let options = effective("{}");
+
+        assert_eq!(options.target, Target::Es5);
+        assert_eq!(options.module, Module::CommonJs);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::Node10);
+        assert_eq!(
+            options.lib,
+            vec![
+                Lib::Es5,
+                Lib::Dom,
+                Lib::WebWorkerImportScripts,
+                Lib::ScriptHost
+            ]
+        );
+        assert!(!options.strict && !options.no_implicit_any && !options.declaration);
+        assert!(!options.use_define_for_class_fields);
+    }
+
+    #[test]
+    fn strict_implies_family() {
+        let options =
+            effective(r#"{"compilerOptions": {"strict": true, "strictNullChecks": false}}"#);
+
+        assert!(options.no_implicit_any);
+        assert!(options.no_implicit_this);
+        assert!(options.strict_function_types);
+        assert!(options.strict_bind_call_apply);
+        assert!(options.strict_property_initialization);
+        assert!(options.always_strict);
+        assert!(options.use_unknown_in_catch_variables);
+        assert!(options.strict_builtin_iterator_return);
+        assert!(!options.strict_null_checks);
+    }
+
+    #[test]
+    fn derives_module_settings() {
+        let options = effective(r#"{"compilerOptions": {"target": "es2022"}}"#);
+        assert_eq!(options.module, Module::Es2015);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::Classic);
+        assert!(options.use_define_for_class_fields);
+        assert_eq!(options.lib[0], Lib::Es2022);
+        assert!(options.lib.contains(&Lib::DomIterable));
+
+        let options = effective(r#"{"compilerOptions": {"module": "nodenext"}}"#);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::NodeNext);
+        assert_eq!(options.module_detection, ModuleDetection::Force);
+        assert!(options.resolve_package_json_exports);
+        assert!(options.es_module_interop);
+        assert!(options.allow_synthetic_default_imports);
+
+        let options = effective(r#"{"compilerOptions": {"module": "node18"}}"#);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::Node16);
+        assert_eq!(options.module_detection, ModuleDetection::Force);
+
+        let options = effective(r#"{"compilerOptions": {"module": "preserve"}}"#);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::Bundler);
+        assert_eq!(options.module_detection, ModuleDetection::Auto);
+        assert!(options.es_module_interop);
+
+        let options = effective(
+            r#"{"compilerOptions": {"module": "esnext", "moduleResolution": "bundler"}}"#,
+        );
+        assert!(!options.es_module_interop);
+        assert!(options.allow_synthetic_default_imports);
+    }
+
+    #[test]
+    fn derives_target_from_node_modules() {
+        let options = effective(r#"{"compilerOptions": {"module": "node16"}}"#);
+        assert_eq!(options.target, Target::Es2022);
+        assert!(options.use_define_for_class_fields);
+        assert_eq!(options.lib[0], Lib::Es2022);
+
+        let options = effective(r#"{"compilerOptions": {"module": "node18"}}"#);
+        assert_eq!(options.target, Target::Es2022);
+
+        let options = effective(r#"{"compilerOptions": {"module": "nodenext"}}"#);
+        assert_eq!(options.target, Target::EsNext);
+        assert_eq!(options.lib[0], Lib::EsNext);
+
+        let options =
+            effective(r#"{"compilerOptions": {"module": "nodenext", "target": "es2020"}}"#);
+        assert_eq!(options.target, Target::Es2020);
+
+        let options = effective(r#"{"compilerOptions": {"module": "esnext"}}"#);
+        assert_eq!(options.target, Target::Es5);
+    }
+
+    #[test]
+    fn follows_version_defaults() {
+        let options = TsConfig::parse_str(r#"{"compilerOptions": {"target": "esnext"}}"#)
+            .unwrap()
+            .compiler_options
+            .unwrap();
+        assert!(
+            options
+                .effective_for(TsVersion::new(4, 3))
+                .use_define_for_class_fields
+        );
+        assert!(
+            !options
+                .effective_for(TsVersion::new(4, 2))
+                .use_define_for_class_fields
+        );
+
+        let options = TsConfig::parse_str(r#"{"compilerOptions": {"strict": true}}"#)
+            .unwrap()
+            .compiler_options
+            .unwrap()
+            .effective_for(TsVersion::new(5, 5));
+        assert!(options.use_unknown_in_catch_variables);
+        assert!(!options.strict_builtin_iterator_return);
+
+        let options = CompilerOptions::default().effective_for(TsVersion::new(4, 9));
+        assert_eq!(options.target, Target::Es3);
+        assert_eq!(options.module_resolution, ModuleResolutionMode::Node);
+    }
+
+    #[test]
+    fn derives_implied_flags() {
+        let options = effective(
+            r#"{"compilerOptions": {"esModuleInterop": true, "composite": true, "checkJs": true, "isolatedModules": true}}"#,
+        );
+        assert!(options.allow_synthetic_default_imports);
+        assert!(options.declaration);
+        assert!(options.incremental);
+        assert!(options.allow_js);
+        assert!(options.preserve_const_enums);
+
+        let options = effective(
+            r#"{"compilerOptions": {"composite": true, "incremental": false, "declaration": false, "noLib": true}}"#,
+        );
+        assert!(options.incremental);
+        assert!(options.declaration);
+        assert!(options.lib.is_empty());
+    }
+
+    #[test]
+    fn derives_isolated_modules() {
+        let options = effective(
+            r#"{"compilerOptions": {"verbatimModuleSyntax": true, "preserveConstEnums": false}}"#,
+        );
+        assert!(options.isolated_modules);
+        assert!(options.preserve_const_enums);
+
+        let options = effective(r#"{"compilerOptions": {"isolatedDeclarations": true}}"#);
+        assert!(!options.isolated_modules);
+        assert!(!options.preserve_const_enums);
+    }
+
+    #[test]
+    fn enables_type_acquisition_for_jsconfig_files() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/type_acquisition/jsconfig.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+
+        assert_eq!(
+            config.effective_type_acquisition(&path),
+            EffectiveTypeAcquisition {
+                enable: true,
+                include: vec!["jest".to_string()],
+                exclude: vec!["jquery".to_string()],
+                disable_filename_based_type_acquisition: true,
+            }
+        );
+        assert!(!config.effective_type_acquisition(&"tsconfig.json").enable);
+
+        let config = TsConfig::parse_str(r#"{"typeAcquisition": true}"#).unwrap();
+        assert!(config.effective_type_acquisition(&"tsconfig.json").enable);
+        let config = TsConfig::parse_str("{}").unwrap();
+        assert_eq!(
+            config.effective_type_acquisition(&"jsconfig.json"),
+            EffectiveTypeAcquisition {
+                enable: true,
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,825 @@
+//! A parser for JSON with comments, the dialect tsc accepts in configuration files.
+//!
+//! On top of plain JSON, `//` and `/* */` comments and trailing commas in objects and arrays are
+//! allowed. String literals are never modified.
+
+use std::fmt;
+use std::ops::Range;
+
+use serde_json::{Map, Number, Value};
+use thiserror::Error;
+
+/// A syntax error in a JSONC document.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{kind}")]
+pub struct SyntaxError {
+    pub kind: SyntaxErrorKind,
+    /// The byte range of the offending token.
+    pub span: Range<usize>,
+}
+
+/// The kinds of [SyntaxError].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxErrorKind {
+    /// A specific token, such as `,` or `:`, was expected.
+    Expected(&'static str),
+    /// An object member did not start with a string key.
+    PropertyAssignmentExpected,
+    /// A value was expected.
+    ValueExpected,
+    /// A string was not closed before the end of the line.
+    UnterminatedString,
+    /// A block comment was not closed.
+    UnterminatedComment,
+    /// A string used single quotes.
+    DoubleQuotesExpected,
+    /// A string contained an invalid escape sequence.
+    InvalidEscape,
+    /// A number was malformed.
+    InvalidNumber,
+    /// A character that cannot start any token.
+    InvalidCharacter,
+    /// The document ended in the middle of a value.
+    UnexpectedEndOfText,
+    /// Something followed the top-level value.
+    UnexpectedToken,
+    /// Objects and arrays were nested deeper than the parser allows.
+    TooDeeplyNested,
+}
+
+impl fmt::Display for SyntaxErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxErrorKind::Expected(token) => write!(f, "'{}' expected", token),
+            SyntaxErrorKind::PropertyAssignmentExpected => {
+                write!(f, "Property assignment expected")
+            }
+            SyntaxErrorKind::ValueExpected => write!(f, "Expression expected"),
+            SyntaxErrorKind::UnterminatedString => write!(f, "Unterminated string literal"),
+            SyntaxErrorKind::UnterminatedComment => write!(f, "'*/' expected"),
+            SyntaxErrorKind::DoubleQuotesExpected => {
+                write!(f, "String literal with double quotes expected")
+            }
+            SyntaxErrorKind::InvalidEscape => write!(f, "Invalid escape sequence"),
+            SyntaxErrorKind::InvalidNumber => write!(f, "Invalid number"),
+            SyntaxErrorKind::InvalidCharacter => write!(f, "Invalid character"),
+            SyntaxErrorKind::UnexpectedEndOfText => write!(f, "Unexpected end of text"),
+            SyntaxErrorKind::UnexpectedToken => write!(f, "Unexpected token"),
+            SyntaxErrorKind::TooDeeplyNested => write!(f, "Nesting too deep"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Colon,
+    Comma,
+    String,
+    Number,
+    True,
+    False,
+    Null,
+    /// A bare word other than `true`, `false` and `null`, which is never valid.
+    Identifier,
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Eof,
+}
+
+impl TokenKind {
+    pub(crate) fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenKind,
+    pub(crate) span: Range<usize>,
+}
+
+/// Splits a document into tokens, including whitespace and comments.
+pub(crate) struct Lexer<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        // A byte order mark is not part of the document.
+        let pos = if text.starts_with('\u{feff}') { 3 } else { 0 };
+        Lexer { text, pos }
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn error(&self, kind: SyntaxErrorKind, start: usize) -> SyntaxError {
+        SyntaxError {
+            kind,
+            span: start..self.pos,
+        }
+    }
+
+    /// Returns the next token, or [TokenKind::Eof] at the end of the document.
+    pub(crate) fn next_token(&mut self) -> Result<Token, SyntaxError> {
+        let start = self.pos;
+        let c = match self.peek_char() {
+            Some(c) => c,
+            None => {
+                return Ok(Token {
+                    kind: TokenKind::Eof,
+                    span: start..start,
+                })
+            }
+        };
+        self.pos += c.len_utf8();
+
+        let kind = match c {
+            '{' => TokenKind::LBrace,
+            '}' => TokenKind::RBrace,
+            '[' => TokenKind::LBracket,
+            ']' => TokenKind::RBracket,
+            ':' => TokenKind::Colon,
+            ',' => TokenKind::Comma,
+            '"' => {
+                self.lex_string(start)?;
+                TokenKind::String
+            }
+            '\'' => {
+                return Err(self.error(SyntaxErrorKind::DoubleQuotesExpected, start));
+            }
+            '/' => match self.peek_char() {
+                Some('/') => {
+                    let end = self.text[self.pos..]
+                        .find(['\n', '\r'])
+                        .map_or(self.text.len(), |i| self.pos + i);
+                    self.pos = end;
+                    TokenKind::LineComment
+                }
+                Some('*') => match self.text[self.pos + 1..].find("*/") {
+                    Some(i) => {
+                        self.pos += 1 + i + 2;
+                        TokenKind::BlockComment
+                    }
+                    None => {
+                        self.pos = self.text.len();
+                        return Err(self.error(SyntaxErrorKind::UnterminatedComment, start));
+                    }
+                },
+                _ => return Err(self.error(SyntaxErrorKind::InvalidCharacter, start)),
+            },
+            '-' | '0'..='9' => {
+                self.eat_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'));
+                if serde_json::from_str::<Number>(&self.text[start..self.pos]).is_err() {
+                    return Err(self.error(SyntaxErrorKind::InvalidNumber, start));
+                }
+                TokenKind::Number
+            }
+            c if c.is_whitespace() => {
+                self.eat_while(char::is_whitespace);
+                TokenKind::Whitespace
+            }
+            c if c.is_alphabetic() || c == '_' || c == '$' => {
+                self.eat_while(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                match &self.text[start..self.pos] {
+                    "true" => TokenKind::True,
+                    "false" => TokenKind::False,
+                    "null" => TokenKind::Null,
+                    _ => TokenKind::Identifier,
+                }
+            }
+            _ => return Err(self.error(SyntaxErrorKind::InvalidCharacter, start)),
+        };
+
+        Ok(Token {
+            kind,
+            span: start..self.pos,
+        })
+    }
+
+    fn eat_while(&mut self, f: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek_char() {
+            if !f(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn lex_string(&mut self, start: usize) -> Result<(), SyntaxError> {
+        loop {
+            match self.peek_char() {
+                None | Some('\n') | Some('\r') => {
+                    return Err(self.error(SyntaxErrorKind::UnterminatedString, start));
+                }
+                Some('"') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek_char() {
+                        Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => self.pos += 1,
+                        Some('u') => {
+                            self.pos += 1;
+                            let digits = self.text[self.pos..]
+                                .chars()
+                                .take(4)
+                                .take_while(char::is_ascii_hexdigit)
+                                .count();
+                            self.pos += digits;
+                            if digits != 4 {
+                                return Err(self.error(SyntaxErrorKind::InvalidEscape, start));
+                            }
+                        }
+                        _ => return Err(self.error(SyntaxErrorKind::InvalidEscape, start)),
+                    }
+                }
+                Some(c) => self.pos += c.len_utf8(),
+            }
+        }
+    }
+}
+
+/// Decodes the contents of a string token, which must have been produced by [Lexer].
+pub(crate) fn unescape(token: &str) -> String {
+    let mut result = String::with_capacity(token.len());
+    let mut chars = token[1..token.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let unit = u16::from_str_radix(&hex, 16).unwrap_or(0xfffd);
+                result.push_str(&decode_utf16_unit(unit, &mut chars));
+            }
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result
+}
+
+/// Decodes a UTF-16 code unit, consuming a following `\uXXXX` low surrogate if it is a high one.
+fn decode_utf16_unit(unit: u16, chars: &mut std::str::Chars) -> String {
+    if (0xd800..0xdc00).contains(&unit) {
+        let rest = chars.as_str();
+        if let Some(low) = rest
+            .strip_prefix("\\u")
+            .and_then(|r| r.get(..4))
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok())
+        {
+            if (0xdc00..0xe000).contains(&low) {
+                *chars = rest[6..].chars();
+                return String::from_utf16_lossy(&[unit, low]);
+            }
+        }
+    }
+    String::from_utf16_lossy(&[unit])
+}
+
+/// How deeply objects and arrays may be nested, so that hostile input cannot overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Parses a JSONC document into a [Value].
+///
+/// As with tsc, a document without any value, such as an empty file, is an empty object.
+pub fn parse(text: &str) -> Result<Value, SyntaxError> {
+    let mut parser = Parser::new(text)?;
+    if parser.token.kind == TokenKind::Eof {
+        return Ok(Value::Object(Map::new()));
+    }
+    let value = parser.parse_value()?;
+    match parser.token.kind {
+        TokenKind::Eof => Ok(value),
+        _ => Err(parser.error(SyntaxErrorKind::UnexpectedToken)),
+    }
+}
+
+/// Finds the byte range of the value at `path` in a JSONC document, each segment being an object
+/// key or an array index. Returns `None` if there is no such value or the document is invalid.
+pub(crate) fn value_span(text: &str, path: &[&str]) -> Option<Range<usize>> {
+    member_span(text, path).map(|member| member.value)
+}
+
+/// Like [value_span], but also returns the span of the key if the value is an object member.
+pub(crate) fn member_span(text: &str, path: &[&str]) -> Option<MemberSpan> {
+    let mut parser = Parser::new(text).ok()?;
+    parser.find(path).ok()?
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MemberSpan {
+    pub(crate) key: Option<Range<usize>>,
+    pub(crate) value: Range<usize>,
+}
+
+/// Parses a JSONC document into a tree of spans, for editing it without disturbing its layout.
+///
+/// A document without any value is [NodeKind::Empty], with an empty span at its end.
+pub(crate) fn parse_tree(text: &str) -> Result<Node, SyntaxError> {
+    let mut parser = Parser::new(text)?;
+    if parser.token.kind == TokenKind::Eof {
+        return Ok(Node {
+            span: parser.token.span.clone(),
+            kind: NodeKind::Empty,
+        });
+    }
+    let node = parser.parse_node()?;
+    match parser.token.kind {
+        TokenKind::Eof => Ok(node),
+        _ => Err(parser.error(SyntaxErrorKind::UnexpectedToken)),
+    }
+}
+
+/// A value in a JSONC document, with the spans of its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Node {
+    pub(crate) span: Range<usize>,
+    pub(crate) kind: NodeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NodeKind {
+    Scalar,
+    Object(Vec<Entry>),
+    Array(Vec<Entry>),
+    /// The root of a document holding only whitespace and comments, which stands for `{}`.
+    Empty,
+}
+
+/// An object member or array element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Entry {
+    /// The decoded key and the span of its string literal, for object members.
+    pub(crate) key: Option<(String, Range<usize>)>,
+    pub(crate) value: Node,
+    /// The span of the comma following the entry, if any.
+    pub(crate) comma: Option<Range<usize>>,
+}
+
+impl Entry {
+    /// Where the entry starts: its key for object members, or else its value.
+    pub(crate) fn start(&self) -> usize {
+        match &self.key {
+            Some((_, span)) => span.start,
+            None => self.value.span.start,
+        }
+    }
+
+    /// Where the entry ends, including its comma.
+    pub(crate) fn end(&self) -> usize {
+        match &self.comma {
+            Some(comma) => comma.end,
+            None => self.value.span.end,
+        }
+    }
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    /// The current non-trivia token.
+    token: Token,
+    /// The end of the last token consumed.
+    last_end: usize,
+    /// How many objects and arrays the current token is in.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Result<Self, SyntaxError> {
+        let mut lexer = Lexer::new(text);
+        let token = next_significant(&mut lexer)?;
+        Ok(Parser {
+            lexer,
+            token,
+            last_end: 0,
+            depth: 0,
+        })
+    }
+
+    fn text(&self) -> &'a str {
+        &self.lexer.text[self.token.span.clone()]
+    }
+
+    fn bump(&mut self) -> Result<(), SyntaxError> {
+        self.last_end = self.token.span.end;
+        self.token = next_significant(&mut self.lexer)?;
+        Ok(())
+    }
+
+    fn error(&self, kind: SyntaxErrorKind) -> SyntaxError {
+        let kind = match self.token.kind {
+            TokenKind::Eof => SyntaxErrorKind::UnexpectedEndOfText,
+            _ => kind,
+        };
+        SyntaxError {
+            kind,
+            span: self.token.span.clone(),
+        }
+    }
+
+    /// Enters an object or array, consuming its opening token.
+    fn enter(&mut self) -> Result<(), SyntaxError> {
+        if self.depth == MAX_DEPTH {
+            return Err(SyntaxError {
+                kind: SyntaxErrorKind::TooDeeplyNested,
+                span: self.token.span.clone(),
+            });
+        }
+        self.depth += 1;
+        self.bump()
+    }
+
+    /// Leaves an object or array, consuming its closing token.
+    fn leave(&mut self) -> Result<(), SyntaxError> {
+        self.depth -= 1;
+        self.bump()
+    }
+
+    fn expect(&mut self, kind: TokenKind, text: &'static str) -> Result<(), SyntaxError> {
+        if self.token.kind != kind {
+            return Err(self.error(SyntaxErrorKind::Expected(text)));
+        }
+        self.bump()
+    }
+
+    fn parse_value(&mut self) -> Result<Value, SyntaxError> {
+        let value = match self.token.kind {
+            TokenKind::LBrace => return self.parse_object(),
+            TokenKind::LBracket => return self.parse_array(),
+            TokenKind::String => Value::String(unescape(self.text())),
+            TokenKind::Number => Value::Number(
+                serde_json::from_str(self.text())
+                    .map_err(|_| self.error(SyntaxErrorKind::InvalidNumber))?,
+            ),
+            TokenKind::True => Value::Bool(true),
+            TokenKind::False => Value::Bool(false),
+            TokenKind::Null => Value::Null,
+            _ => return Err(self.error(SyntaxErrorKind::ValueExpected)),
+        };
+        self.bump()?;
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<Value, SyntaxError> {
+        self.enter()?;
+        let mut map = Map::new();
+        while self.token.kind != TokenKind::RBrace {
+            if self.token.kind != TokenKind::String {
+                return Err(self.error(SyntaxErrorKind::PropertyAssignmentExpected));
+            }
+            let key = unescape(self.text());
+            self.bump()?;
+            self.expect(TokenKind::Colon, ":")?;
+            let value = self.parse_value()?;
+            map.insert(key, value);
+
+            match self.token.kind {
+                TokenKind::Comma => self.bump()?,
+                TokenKind::RBrace => {}
+                _ => return Err(self.error(SyntaxErrorKind::Expected(","))),
+            }
+        }
+        self.leave()?;
+        Ok(Value::Object(map))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, SyntaxError> {
+        self.enter()?;
+        let mut items = Vec::new();
+        while self.token.kind != TokenKind::RBracket {
+            items.push(self.parse_value()?);
+
+            match self.token.kind {
+                TokenKind::Comma => self.bump()?,
+                TokenKind::RBracket => {}
+                _ => return Err(self.error(SyntaxErrorKind::Expected(","))),
+            }
+        }
+        self.leave()?;
+        Ok(Value::Array(items))
+    }
+}
+
+impl Parser<'_> {
+    fn parse_node(&mut self) -> Result<Node, SyntaxError> {
+        let start = self.token.span.start;
+        let close = match self.token.kind {
+            TokenKind::LBrace => TokenKind::RBrace,
+            TokenKind::LBracket => TokenKind::RBracket,
+            _ => {
+                self.parse_value()?;
+                return Ok(Node {
+                    span: start..self.last_end,
+                    kind: NodeKind::Scalar,
+                });
+            }
+        };
+        let is_object = close == TokenKind::RBrace;
+
+        self.enter()?;
+        let mut entries = Vec::new();
+        while self.token.kind != close {
+            let key = if is_object {
+                if self.token.kind != TokenKind::String {
+                    return Err(self.error(SyntaxErrorKind::PropertyAssignmentExpected));
+                }
+                let key = (unescape(self.text()), self.token.span.clone());
+                self.bump()?;
+                self.expect(TokenKind::Colon, ":")?;
+                Some(key)
+            } else {
+                None
+            };
+            let value = self.parse_node()?;
+            let comma = match self.token.kind {
+                TokenKind::Comma => {
+                    let comma = self.token.span.clone();
+                    self.bump()?;
+                    Some(comma)
+                }
+                kind if kind == close => None,
+                _ => return Err(self.error(SyntaxErrorKind::Expected(","))),
+            };
+            entries.push(Entry { key, value, comma });
+        }
+        self.leave()?;
+
+        Ok(Node {
+            span: start..self.last_end,
+            kind: if is_object {
+                NodeKind::Object(entries)
+            } else {
+                NodeKind::Array(entries)
+            },
+        })
+    }
+
+    /// Consumes a value, returning the span of the value at `path` within it if there is one.
+    fn find(&mut self, path: &[&str]) -> Result<Option<MemberSpan>, SyntaxError> {
+        let (segment, rest) = match path.split_first() {
+            Some(split) => split,
+            None => {
+                let start = self.token.span.start;
+                self.parse_value()?;
+                return Ok(Some(MemberSpan {
+                    key: None,
+                    value: start..self.last_end,
+                }));
+            }
+        };
+
+        let mut found = None;
+        match self.token.kind {
+            TokenKind::LBrace => {
+                self.bump()?;
+                while self.token.kind != TokenKind::RBrace {
+                    if self.token.kind != TokenKind::String {
+                        return Err(self.error(SyntaxErrorKind::PropertyAssignmentExpected));
+                    }
+                    let key = unescape(self.text());
+                    let key_span = self.token.span.clone();
+                    self.bump()?;
+                    self.expect(TokenKind::Colon, ":")?;
+                    if key == *segment {
+                        // Later duplicates win, as they do when parsing.
+                        found = self.find(rest)?;
+                        if let (Some(member), true) = (&mut found, rest.is_empty()) {
+                            member.key = Some(key_span);
+                        }
+                    } else {
+                        self.parse_value()?;
+                    }
+                    if self.token.kind != TokenKind::RBrace {
+                        self.expect(TokenKind::Comma, ",")?;
+                    }
+                }
+                self.bump()?;
+            }
+            TokenKind::LBracket => {
+                self.bump()?;
+                let mut index = 0;
+                while self.token.kind != TokenKind::RBracket {
+                    if segment.parse() == Ok(index) {
+                        found = self.find(rest)?;
+                    } else {
+                        self.parse_value()?;
+                    }
+                    index += 1;
+                    if self.token.kind != TokenKind::RBracket {
+                        self.expect(TokenKind::Comma, ",")?;
+                    }
+                }
+                self.bump()?;
+            }
+            _ => {
+                self.parse_value()?;
+            }
+        }
+        Ok(found)
+    }
+}
+
+fn next_significant(lexer: &mut Lexer) -> Result<Token, SyntaxError> {
+    loop {
+        let token = lexer.next_token()?;
+        if !token.kind.is_trivia() {
+            return Ok(token);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_plain_json() {
+        let value = parse(r#"{"a": [1, -2.5e3, true, false, null], "b": {"c": "d"}}"#).unwrap();
+        assert_eq!(
+            value,
+            json!({"a": [1, -2.5e3, true, false, null], "b": {"c": "d"}})
+        );
+    }
+
+    #[test]
+    fn allows_comments() {
+        let value =
+            parse("// leading\n{\"a\": /* inline */ 1, // trailing\n\"b\": 2 /* multi\nline */}")
+                .unwrap();
+        assert_eq!(value, json!({"a": 1, "b": 2}));
+    }
+
+    #[test]
+    fn allows_trailing_commas() {
+        assert_eq!(
+            parse(r#"{"lib": ["dom",], "a": 1,}"#).unwrap(),
+            json!({"lib": ["dom"], "a": 1})
+        );
+        assert_eq!(parse("[1,\n]").unwrap(), json!([1]));
+    }
+
+    #[test]
+    fn leaves_strings_untouched() {
+        assert_eq!(
+            parse(r#"{"jsxFactory": "h,}", "glob": "src/**/*.ts", "url": "http://x//y"}"#).unwrap(),
+            json!({"jsxFactory": "h,}", "glob": "src/**/*.ts", "url": "http://x//y"})
+        );
+    }
+
+    #[test]
+    fn decodes_escapes() {
+        assert_eq!(
+            parse(r#""a\"b\\c\/d\né😀""#).unwrap(),
+            json!("a\"b\\c/d\né😀")
+        );
+    }
+
+    #[test]
+    fn skips_byte_order_mark() {
+        assert_eq!(parse("\u{feff}{}").unwrap(), json!({}));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        let kind = |text| parse(text).unwrap_err().kind;
+
+        assert_eq!(kind(r#"{"a": 1 "b": 2}"#), SyntaxErrorKind::Expected(","));
+        assert_eq!(kind(r#"{"a" 1}"#), SyntaxErrorKind::Expected(":"));
+        assert_eq!(
+            kind(r#"{a: 1}"#),
+            SyntaxErrorKind::PropertyAssignmentExpected
+        );
+        assert_eq!(kind(r#"{"a": NaN}"#), SyntaxErrorKind::ValueExpected);
+        assert_eq!(
+            kind(r#"{1: 1}"#),
+            SyntaxErrorKind::PropertyAssignmentExpected
+        );
+        assert_eq!(kind(r#"{"a": }"#), SyntaxErrorKind::ValueExpected);
+        assert_eq!(kind(r#"{"a": 'b'}"#), SyntaxErrorKind::DoubleQuotesExpected);
+        assert_eq!(
+            kind("{\"a\": \"b\n\"}"),
+            SyntaxErrorKind::UnterminatedString
+        );
+        assert_eq!(kind(r#"{"a": "\q"}"#), SyntaxErrorKind::InvalidEscape);
+        assert_eq!(kind(r#"{"a": 01}"#), SyntaxErrorKind::InvalidNumber);
+        assert_eq!(kind("{} /* open"), SyntaxErrorKind::UnterminatedComment);
+        assert_eq!(kind(r#"{"a": 1"#), SyntaxErrorKind::UnexpectedEndOfText);
+        assert_eq!(kind("{} {}"), SyntaxErrorKind::UnexpectedToken);
+        assert_eq!(kind("{,}"), SyntaxErrorKind::PropertyAssignmentExpected);
+        assert_eq!(kind("#"), SyntaxErrorKind::InvalidCharacter);
+    }
+
+    #[test]
+    fn parses_documents_without_value_as_empty_objects() {
+        assert_eq!(parse("").unwrap(), json!({}));
+        assert_eq!(parse(" \n\t").unwrap(), json!({}));
+        assert_eq!(parse("// nothing\n/* here */").unwrap(), json!({}));
+        assert_eq!(
+            parse("/* open").unwrap_err().kind,
+            SyntaxErrorKind::UnterminatedComment
+        );
+    }
+
+    #[test]
+    fn limits_nesting() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+
+        let error = parse(&nested(MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(error.kind, SyntaxErrorKind::TooDeeplyNested);
+        assert_eq!(error.span, MAX_DEPTH..MAX_DEPTH + 1);
+
+        let deep = "[{\"a\": ".repeat(100_000);
+        assert_eq!(
+            parse(&deep).unwrap_err().kind,
+            SyntaxErrorKind::TooDeeplyNested
+        );
+        assert_eq!(
+            parse_tree(&deep).unwrap_err().kind,
+            SyntaxErrorKind::TooDeeplyNested
+        );
+        assert_eq!(value_span(&deep, &["a"]), None);
+    }
+
+    #[test]
+    fn finds_value_spans() {
+        let text = r#"{
+            // comment
+            "compilerOptions": {"target": "es5", "lib": ["dom", "es2015"],},
+            "include": ["src"],
+            "include": ["lib"]
+        }"#;
+        let span = |path: &[&str]| value_span(text, path).map(|span| &text[span]);
+
+        assert_eq!(span(&["compilerOptions", "target"]), Some(r#""es5""#));
+        assert_eq!(span(&["compilerOptions", "lib", "1"]), Some(r#""es2015""#));
+        assert_eq!(span(&["include"]), Some(r#"["lib"]"#));
+        assert_eq!(span(&["compilerOptions", "strict"]), None);
+        assert_eq!(span(&["include", "x"]), None);
+        assert_eq!(span(&[]), Some(text));
+
+        let member = member_span(text, &["compilerOptions", "target"]).unwrap();
+        assert_eq!(&text[member.key.unwrap()], r#""target""#);
+        assert_eq!(member_span(text, &[]).unwrap().key, None);
+    }
+
+    #[test]
+    fn parses_trees() {
+        let text = r#"{"a": [1, 2,], /* c */ "b": {}}"#;
+        let node = parse_tree(text).unwrap();
+        assert_eq!(node.span, 0..text.len());
+
+        let members = match node.kind {
+            NodeKind::Object(members) => members,
+            other => panic!("expected an object, got {:?}", other),
+        };
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].key.as_ref().unwrap().0, "a");
+        assert_eq!(
+            &text[members[0].start()..members[0].end()],
+            r#""a": [1, 2,],"#
+        );
+        assert_eq!(&text[members[1].start()..members[1].end()], r#""b": {}"#);
+
+        match &members[0].value.kind {
+            NodeKind::Array(items) => {
+                assert_eq!(items.len(), 2);
+                assert!(items[1].comma.is_some());
+            }
+            other => panic!("expected an array, got {:?}", other),
+        }
+        assert_eq!(
+            parse_tree("[1 2]").unwrap_err().kind,
+            SyntaxErrorKind::Expected(",")
+        );
+    }
+
+    #[test]
+    fn reports_spans() {
+        let text = r#"{"a": 1 "b": 2}"#;
+        let err = parse(text).unwrap_err();
+        assert_eq!(&text[err.span], r#""b""#);
+    }
+}
@@ -17,16 +17,40 @@
 //!
 //! ```
 
-use std::path::Path;
-use std::{collections::HashMap, io::Read};
-
-use json_comments::StripComments;
-use regex::Regex;
-use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
 use thiserror::Error;
 
+mod cli;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod document;
+mod effective;
+mod jsonc;
+mod provenance;
+mod rebase;
+mod resolve;
+mod show_config;
+mod version;
+
+pub use cli::{BuildOptions, CommandLine, CommandLineError};
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::{unknown_compiler_options, Diagnostic, DiagnosticStyle};
+pub use document::{Document, EditError};
+pub use effective::{EffectiveCompilerOptions, EffectiveTypeAcquisition};
+pub use jsonc::{SyntaxError, SyntaxErrorKind};
+pub use provenance::{parse_file_with_provenance, FieldSource, Provenance};
+pub use show_config::{flatten_file, show_config};
+pub use version::{ParseVersionError, TsVersion, VersionIssue};
+
 pub type Result<T, E = ConfigError> = std::result::Result<T, E>;
 
 /// Errors when parsing TsConfig files.
@@ -36,23 +60,159 @@ pub type Result<T, E = ConfigError> = std::result::Result<T, E>;
 pub enum ConfigError {
     #[error("Could not parse configuration file")]
     ParseError(#[from] serde_json::Error),
-    #[error("Could not read file")]
-    CouldNotFindFile(#[from] std::io::Error),
+    /// The file is not valid JSON with comments.
+    #[error("{location}: {error}")]
+    SyntaxError {
+        error: SyntaxError,
+        location: Box<Location>,
+    },
+    /// A field has the wrong type or an unrecognised value.
+    #[error("{location}: {message}")]
+    InvalidValue {
+        message: String,
+        /// The dotted path of the field, e.g. `compilerOptions.strict`, or an empty string if it
+        /// could not be told.
+        field: String,
+        location: Box<Location>,
+    },
+    #[error("Could not read file {}", .path.display())]
+    CouldNotFindFile {
+        path: PathBuf,
+        /// The files whose `extends` led to `path`, outermost first.
+        extends_chain: Vec<PathBuf>,
+        #[source]
+        source: std::io::Error,
+    },
     #[error("Could not convert path into UTF-8: {0}")]
     InvalidPath(String),
+    /// An `extends` specifier did not resolve to a file. The location is that of the specifier.
+    #[error("{location}: Could not find base configuration '{specifier}'")]
+    ExtendsNotFound {
+        specifier: String,
+        location: Box<Location>,
+    },
+    /// A configuration (indirectly) extends itself. Holds the canonicalized paths of the cycle,
+    /// starting and ending with the same file.
+    #[error("Circularity detected while resolving configuration: {}", display_cycle(.0))]
+    CircularExtends(Vec<PathBuf>),
+}
+
+fn display_cycle(cycle: &[PathBuf]) -> String {
+    cycle
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+impl ConfigError {
+    /// The place in a configuration file the error points at, if any.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ConfigError::SyntaxError { location, .. }
+            | ConfigError::InvalidValue { location, .. }
+            | ConfigError::ExtendsNotFound { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    fn io(path: &Path, extends_chain: &[PathBuf], source: std::io::Error) -> Self {
+        ConfigError::CouldNotFindFile {
+            path: path.to_path_buf(),
+            extends_chain: extends_chain.to_vec(),
+            source,
+        }
+    }
+}
+
+/// A position in a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The file, or `None` when parsing a string.
+    pub path: Option<PathBuf>,
+    /// The byte range of the offending token.
+    pub span: Range<usize>,
+    /// The 1-based line of the start of `span`.
+    pub line: usize,
+    /// The 1-based column, in characters, of the start of `span`.
+    pub column: usize,
+    /// The canonicalized paths of the files whose `extends` led to `path`, outermost first.
+    pub extends_chain: Vec<PathBuf>,
+}
+
+impl Location {
+    fn new(
+        path: Option<&Path>,
+        text: &str,
+        span: Range<usize>,
+        extends_chain: &[PathBuf],
+    ) -> Box<Self> {
+        let before = &text[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Box::new(Location {
+            path: path.map(Path::to_path_buf),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            span,
+            extends_chain: extends_chain.to_vec(),
+        })
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
 }
 
 /// The main struct representing a parsed .tsconfig file.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exclude: Option<Vec<String>>,
-    pub extends: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<Extends>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub include: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub references: Option<References>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub type_acquisition: Option<TypeAcquisition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compiler_options: Option<CompilerOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_options: Option<WatchOptions>,
+    /// Top-level fields not described above, such as `$schema`, `compileOnSave` or the settings
+    /// of other tools like `ts-node` and `angularCompilerOptions`.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// The base configuration(s) a .tsconfig file inherits from.
+///
+/// Since TypeScript 5.0 `extends` may be an array, in which case each base is applied in order and
+/// later entries override earlier ones.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Extends {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl Extends {
+    /// The specifiers of every base configuration, in the order they are applied.
+    pub fn as_slice(&self) -> &[String] {
+        match self {
+            Extends::Single(s) => std::slice::from_ref(s),
+            Extends::Multiple(v) => v,
+        }
+    }
 }
 
 impl TsConfig {
@@ -111,8 +271,17 @@ impl TsConfig {
     ///
     /// ```
     pub fn parse_file<P: AsRef<Path>>(path: &P) -> Result<TsConfig> {
-        let values = parse_file_to_value(path)?;
-        let cfg = serde_json::from_value(values)?;
+        let layers = load_rebased_layers(path.as_ref())?;
+        // Check each file on its own so that errors point at the file that caused them.
+        for layer in &layers {
+            deserialize_located::<TsConfig>(
+                layer.value.clone(),
+                &layer.source,
+                Some(&layer.path),
+                &layer.extends_chain,
+            )?;
+        }
+        let cfg = serde_json::from_value(merge_layers(layers))?;
         Ok(cfg)
     }
 
@@ -130,26 +299,52 @@ impl TsConfig {
     ///```
     ///
     pub fn parse_str(json: &str) -> Result<TsConfig> {
-        // Remove trailing commas from objects.
-        let re = Regex::new(r",(?P<valid>\s*})").unwrap();
-        let mut stripped = String::with_capacity(json.len());
-        StripComments::new(json.as_bytes()).read_to_string(&mut stripped)?;
-        let stripped = re.replace_all(&stripped, "$valid");
-        let r: TsConfig = serde_json::from_str(&stripped)?;
+        let value = parse_to_value(json)?;
+        let r: TsConfig = deserialize_located(value, json, None, &[])?;
         Ok(r)
     }
 }
 
-fn merge(a: &mut Value, b: Value) {
-    match (a, b) {
-        (&mut Value::Object(ref mut a), Value::Object(b)) => {
-            for (k, v) in b {
-                merge(a.entry(k).or_insert(Value::Null), v);
-            }
+/// Top-level sections whose keys are inherited individually.
+const MERGED_SECTIONS: &[&str] = &["compilerOptions", "watchOptions", "typeAcquisition"];
+
+/// Top-level fields that are never inherited from a base configuration.
+const NON_INHERITED_FIELDS: &[&str] = &["extends", "references"];
+
+/// Merges the base configuration `base` into `config`, following tsc's inheritance rules:
+///
+/// * Each compiler option (and each key of `watchOptions` and `typeAcquisition`) is inherited
+///   unless `config` sets it, even to `null`, which unsets it. Options are replaced wholesale, so
+///   arrays such as `lib`, `types` and `rootDirs` and the `paths` map are never combined
+///   element-wise.
+/// * `files`, `include` and `exclude` are inherited individually, and replaced wholesale.
+/// * `extends` and `references` are never inherited.
+fn merge_config(config: &mut Value, base: Value) {
+    let (config, base) = match (config, base) {
+        (Value::Object(config), Value::Object(base)) => (config, base),
+        _ => return,
+    };
+
+    for (key, base_value) in base {
+        if NON_INHERITED_FIELDS.contains(&key.as_str()) {
+            continue;
         }
-        (a, b) => {
-            if let Value::Null = a {
-                *a = b;
+
+        let value = config.entry(key.as_str()).or_insert(Value::Null);
+        match (value, base_value) {
+            (Value::Object(section), Value::Object(base_section))
+                if MERGED_SECTIONS.contains(&key.as_str()) =>
+            {
+                for (option, base_option) in base_section {
+                    if !section.contains_key(&option) {
+                        section.insert(option, base_option);
+                    }
+                }
+            }
+            (value, base_value) => {
+                if value.is_null() {
+                    *value = base_value;
+                }
             }
         }
     }
@@ -158,6 +353,12 @@ fn merge(a: &mut Value, b: Value) {
 /// Parses a .tsconfig file into a [serde_json::Value].
 ///
 /// The `extends` field will be respected, allowing for one .tsconfig file to inherit properties from another.
+/// If `extends` is an array, each base is applied in order, with later bases overriding earlier ones.
+/// Specifiers that are not relative paths are looked up as packages in `node_modules`, as tsc does.
+/// Path-valued options inherited from a base in another directory, such as `outDir` or `include`, are
+/// rewritten to be relative to the directory of `path` rather than that of the base.
+/// Once the chain is merged, the `${configDir}` template in path-valued options is replaced, making
+/// them relative to the directory of `path` as well, or to `baseUrl` for the substitutions of `paths`.
 /// Comments and trailing commas are both allowed, although they are not valid JSON.
 /// ## Example
 ///
@@ -203,33 +404,186 @@ fn merge(a: &mut Value, b: Value) {
 ///
 /// ```
 pub fn parse_file_to_value<P: AsRef<Path>>(path: &P) -> Result<Value> {
-    let s = std::fs::read_to_string(path)?;
-    let mut value = parse_to_value(&s)?;
-
-    if let Value::String(s) = &value["extends"] {
-        // This may or may not have a `.json` extension
-        let extends_path_unchecked = path
-            .as_ref()
-            .parent()
-            .unwrap_or_else(|| Path::new(""))
-            .join(s);
-
-        let extends_path_str = extends_path_unchecked.to_str().ok_or_else(|| {
-            ConfigError::InvalidPath(extends_path_unchecked.to_string_lossy().to_string())
+    let layers = load_rebased_layers(path.as_ref())?;
+    Ok(merge_layers(layers))
+}
+
+/// Loads the `extends` chain of the file at `path`, with paths rebased, see [rebase_layers].
+fn load_rebased_layers(path: &Path) -> Result<Vec<Layer>> {
+    let mut layers = Vec::new();
+    load_layers(path, &mut Vec::new(), &mut layers)?;
+    rebase_layers(path, &mut layers)?;
+    Ok(layers)
+}
+
+/// A single file of an `extends` chain, as written.
+struct Layer {
+    path: PathBuf,
+    value: Value,
+    /// The text of the file, to locate errors in.
+    source: String,
+    /// The canonicalized paths of the files whose `extends` led to this one.
+    extends_chain: Vec<PathBuf>,
+}
+
+/// Loads the file at `path` and everything it extends, pushing one [Layer] per file in the order
+/// they should be applied: each base before the files extending it, and earlier entries of an
+/// `extends` array before later ones.
+///
+/// `ancestors` holds the canonicalized paths of the files currently being extended, so that a file
+/// which ends up extending itself is reported instead of recursing forever.
+fn load_layers(path: &Path, ancestors: &mut Vec<PathBuf>, layers: &mut Vec<Layer>) -> Result<()> {
+    let s = std::fs::read_to_string(path).map_err(|e| ConfigError::io(path, ancestors, e))?;
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| ConfigError::io(path, ancestors, e))?;
+    if let Some(start) = ancestors.iter().position(|p| *p == canonical) {
+        let mut cycle = ancestors[start..].to_vec();
+        cycle.push(canonical);
+        return Err(ConfigError::CircularExtends(cycle));
+    }
+
+    let value = jsonc::parse(&s).map_err(|error| ConfigError::SyntaxError {
+        location: Location::new(Some(path), &s, error.span.clone(), ancestors),
+        error,
+    })?;
+
+    let specifiers: Vec<(Vec<String>, String)> = match &value["extends"] {
+        Value::String(s) => vec![(vec!["extends".to_string()], s.clone())],
+        Value::Array(a) => a
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| {
+                v.as_str()
+                    .map(|s| (vec!["extends".to_string(), i.to_string()], s.to_string()))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let extends_chain = ancestors.clone();
+    ancestors.push(canonical);
+    for (field, specifier) in specifiers {
+        let extends_path = resolve::resolve_extends_path(path, &specifier)?.ok_or_else(|| {
+            let field: Vec<&str> = field.iter().map(String::as_str).collect();
+            let span = jsonc::value_span(&s, &field).unwrap_or(0..0);
+            ConfigError::ExtendsNotFound {
+                specifier: specifier.clone(),
+                location: Location::new(Some(path), &s, span, &extends_chain),
+            }
         })?;
+        load_layers(&extends_path, ancestors, layers)?;
+    }
+    ancestors.pop();
+
+    layers.push(Layer {
+        path: path.to_path_buf(),
+        value,
+        source: s,
+        extends_chain,
+    });
+    Ok(())
+}
 
-        // Append the extension if it doesn't already have it
-        let extends_path = if extends_path_str.ends_with(&".json") {
-            extends_path_unchecked
-        } else {
-            let with_ext = extends_path_str.to_string() + ".json";
-            Path::new(with_ext.as_str()).to_path_buf()
-        };
-        let extends_value = parse_file_to_value(&extends_path)?;
-        merge(&mut value, extends_value);
+/// Deserializes a value parsed from `source`, pointing any error at the offending field.
+fn deserialize_located<T: DeserializeOwned>(
+    value: Value,
+    source: &str,
+    path: Option<&Path>,
+    extends_chain: &[PathBuf],
+) -> Result<T> {
+    let error = match serde_json::from_value(value.clone()) {
+        Ok(t) => return Ok(t),
+        Err(error) => error,
+    };
+
+    let field = invalid_field::<T>(&value);
+    let field: Vec<&str> = field.iter().map(String::as_str).collect();
+    let span = jsonc::value_span(source, &field).unwrap_or(0..0);
+    Err(ConfigError::InvalidValue {
+        message: error.to_string(),
+        field: field.join("."),
+        location: Location::new(path, source, span, extends_chain),
+    })
+}
+
+/// Finds the field of `value` that fails to deserialize, by trying each field (and each option
+/// of the merged sections) on its own.
+fn invalid_field<T: DeserializeOwned>(value: &Value) -> Vec<String> {
+    let fails = |v: Value| serde_json::from_value::<T>(v).is_err();
+    let object = match value {
+        Value::Object(object) => object,
+        _ => return Vec::new(),
+    };
+
+    for (key, field) in object {
+        if !fails(serde_json::json!({ key: field })) {
+            continue;
+        }
+        if let Value::Object(section) = field {
+            for (option, option_value) in section {
+                if fails(serde_json::json!({ key: { option: option_value } })) {
+                    return vec![key.clone(), option.clone()];
+                }
+            }
+        }
+        return vec![key.clone()];
+    }
+    Vec::new()
+}
+
+/// Merges an `extends` chain, later layers overriding earlier ones.
+fn merge_layers(layers: Vec<Layer>) -> Value {
+    let mut merged = Value::Object(serde_json::Map::new());
+    for Layer { mut value, .. } in layers {
+        merge_config(&mut value, merged);
+        merged = value;
     }
+    merged
+}
+
+/// Rebases the path-valued options of every layer so that they are relative to the directory of
+/// `path`, the top-level file, no matter which file declared them. This is also the directory
+/// `${configDir}` refers to.
+fn rebase_layers(path: &Path, layers: &mut [Layer]) -> Result<()> {
+    let top_dir = layer_dir(path)?;
+    // `paths` are relative to `baseUrl` if there is one, or else to the file declaring them.
+    let has_base_url = layers
+        .iter()
+        .any(|layer| layer.value["compilerOptions"]["baseUrl"].is_string());
+
+    for layer in layers.iter_mut() {
+        let dir = layer_dir(&layer.path)?;
+        if dir != top_dir {
+            let rel = rebase::relative(&top_dir, &dir);
+            rebase::rebase_config(&mut layer.value, &rel);
+            if !has_base_url {
+                rebase::rebase_paths_option(&mut layer.value, &rel);
+            }
+        }
+        rebase::substitute_config_dir(&mut layer.value);
+    }
+
+    // `${configDir}` in `paths` has to be made relative to the effective `baseUrl`, which is only
+    // known once every layer has been rebased.
+    let base_url = layers
+        .iter()
+        .rev()
+        .find_map(|layer| layer.value["compilerOptions"]["baseUrl"].as_str());
+    let paths_rel = match base_url {
+        Some(base_url) => rebase::relative(&rebase::normalize(&top_dir.join(base_url)), &top_dir),
+        None => PathBuf::new(),
+    };
+    for layer in layers.iter_mut() {
+        rebase::substitute_config_dir_in_paths(&mut layer.value, &paths_rel);
+    }
+
+    Ok(())
+}
 
-    Ok(value)
+fn layer_dir(path: &Path) -> Result<PathBuf> {
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    rebase::absolute(dir).map_err(|e| ConfigError::io(dir, &[], e))
 }
 
 /// Parse a JSON string into a single [serde_json::Value].
@@ -249,13 +603,10 @@ pub fn parse_file_to_value<P: AsRef<Path>>(path: &P) -> Result<Value> {
 ///```
 ///
 pub fn parse_to_value(json: &str) -> Result<Value> {
-    // Remove trailing commas from objects.
-    let re = Regex::new(r",(?P<valid>\s*})").unwrap();
-    let mut stripped = String::with_capacity(json.len());
-    StripComments::new(json.as_bytes()).read_to_string(&mut stripped)?;
-    let stripped = re.replace_all(&stripped, "$valid");
-    let r: Value = serde_json::from_str(&stripped)?;
-    Ok(r)
+    jsonc::parse(json).map_err(|error| ConfigError::SyntaxError {
+        location: Location::new(None, json, error.span.clone(), &[]),
+        error,
+    })
 }
 
 /// Project references setting  
@@ -265,7 +616,7 @@ pub fn parse_to_value(json: &str) -> Result<Value> {
 /// between components, and organize your code in new and improved ways.
 ///
 /// You can read more about how references works in the Project References section of [the handbook](https://www.typescriptlang.org/docs/handbook/project-references.html).
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum References {
     Bool(bool),
@@ -279,9 +630,10 @@ pub enum References {
 /// between components, and organize your code in new and improved ways.
 ///
 /// You can read more about how references works in the Project References section of [the handbook](https://www.typescriptlang.org/docs/handbook/project-references.html).
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Reference {
     pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prepend: Option<bool>,
 }
 
@@ -333,146 +685,663 @@ pub struct Reference {
 ///
 /// This means that having a file like jquery.js in your project would not automatically download the types for JQuery from DefinitelyTyped.
 ///
-#[derive(Deserialize, Debug, Clone)]
+/// Like compiler options, each of these settings is inherited through `extends` on its own. When
+/// `enable` is not set, type acquisition is only enabled for jsconfig.json files, see
+/// [TsConfig::effective_type_acquisition].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
 pub enum TypeAcquisition {
     Bool(bool),
     Object {
-        enable: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        enable: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         include: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         exclude: Option<Vec<String>>,
+        #[serde(
+            rename = "disableFilenameBasedTypeAcquisition",
+            skip_serializing_if = "Option::is_none"
+        )]
         disable_filename_based_type_acquisition: Option<bool>,
     },
 }
 
+/// How `tsc --watch` and editors watch files and directories for changes.
+///
+/// These settings are inherited through `extends` option by option, like compiler options.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_file: Option<WatchFileKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_directory: Option<WatchDirectoryKind>,
+    /// The polling strategy to fall back to when the system runs out of native file watchers, or
+    /// does not support them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_polling: Option<PollingWatchKind>,
+    /// Synchronously call callbacks and update the state of directory watchers on platforms that
+    /// don't support recursive watching natively.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synchronous_watch_directory: Option<bool>,
+    /// Globs of directories not to watch, relative to the top-level configuration once parsed
+    /// with [TsConfig::parse_file].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_directories: Option<Vec<String>>,
+    /// Globs of files not to watch, relative to the top-level configuration once parsed with
+    /// [TsConfig::parse_file].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_files: Option<Vec<String>>,
+    /// Options not described above.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// The strategy for watching individual files.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WatchFileKind {
+    /// Check every file for changes several times a second at a fixed interval.
+    FixedPollingInterval,
+    /// Check every file for changes several times a second, but less often for some kinds of files.
+    PriorityPollingInterval,
+    /// Check files that change rarely less often.
+    DynamicPriorityPolling,
+    /// Check a fixed number of files at a time at a fixed interval.
+    FixedChunkSizePolling,
+    /// Use the native file system events of the operating system, the default.
+    UseFsEvents,
+    /// Listen to native events on the directories containing the files.
+    UseFsEventsOnParentDirectory,
+    Other(String),
+}
+
+impl WatchFileKind {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WatchFileKind::FixedPollingInterval => "fixedPollingInterval",
+            WatchFileKind::PriorityPollingInterval => "priorityPollingInterval",
+            WatchFileKind::DynamicPriorityPolling => "dynamicPriorityPolling",
+            WatchFileKind::FixedChunkSizePolling => "fixedChunkSizePolling",
+            WatchFileKind::UseFsEvents => "useFsEvents",
+            WatchFileKind::UseFsEventsOnParentDirectory => "useFsEventsOnParentDirectory",
+            WatchFileKind::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for WatchFileKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "FIXEDPOLLINGINTERVAL" => WatchFileKind::FixedPollingInterval,
+            "PRIORITYPOLLINGINTERVAL" => WatchFileKind::PriorityPollingInterval,
+            "DYNAMICPRIORITYPOLLING" => WatchFileKind::DynamicPriorityPolling,
+            "FIXEDCHUNKSIZEPOLLING" => WatchFileKind::FixedChunkSizePolling,
+            "USEFSEVENTS" => WatchFileKind::UseFsEvents,
+            "USEFSEVENTSONPARENTDIRECTORY" => WatchFileKind::UseFsEventsOnParentDirectory,
+            _ => WatchFileKind::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// The strategy for watching directory trees on systems without native recursive watching.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WatchDirectoryKind {
+    /// Use the native file system events of the operating system, the default.
+    UseFsEvents,
+    /// Check every directory for changes several times a second at a fixed interval.
+    FixedPollingInterval,
+    /// Check directories that change rarely less often.
+    DynamicPriorityPolling,
+    /// Check a fixed number of directories at a time at a fixed interval.
+    FixedChunkSizePolling,
+    Other(String),
+}
+
+impl WatchDirectoryKind {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WatchDirectoryKind::UseFsEvents => "useFsEvents",
+            WatchDirectoryKind::FixedPollingInterval => "fixedPollingInterval",
+            WatchDirectoryKind::DynamicPriorityPolling => "dynamicPriorityPolling",
+            WatchDirectoryKind::FixedChunkSizePolling => "fixedChunkSizePolling",
+            WatchDirectoryKind::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for WatchDirectoryKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "USEFSEVENTS" => WatchDirectoryKind::UseFsEvents,
+            "FIXEDPOLLINGINTERVAL" => WatchDirectoryKind::FixedPollingInterval,
+            "DYNAMICPRIORITYPOLLING" => WatchDirectoryKind::DynamicPriorityPolling,
+            "FIXEDCHUNKSIZEPOLLING" => WatchDirectoryKind::FixedChunkSizePolling,
+            _ => WatchDirectoryKind::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// The polling strategy used when native file system events are unavailable.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PollingWatchKind {
+    /// Check every file several times a second at a fixed interval.
+    FixedInterval,
+    /// Check every file several times a second, but less often for some kinds of files.
+    PriorityInterval,
+    /// Check files that change rarely less often.
+    DynamicPriority,
+    /// Check a fixed number of files at a time at a fixed interval.
+    FixedChunkSize,
+    Other(String),
+}
+
+impl PollingWatchKind {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PollingWatchKind::FixedInterval => "fixedInterval",
+            PollingWatchKind::PriorityInterval => "priorityInterval",
+            PollingWatchKind::DynamicPriority => "dynamicPriority",
+            PollingWatchKind::FixedChunkSize => "fixedChunkSize",
+            PollingWatchKind::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for PollingWatchKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "FIXEDINTERVAL" => PollingWatchKind::FixedInterval,
+            "PRIORITYINTERVAL" => PollingWatchKind::PriorityInterval,
+            "DYNAMICPRIORITY" => PollingWatchKind::DynamicPriority,
+            "FIXEDCHUNKSIZE" => PollingWatchKind::FixedChunkSize,
+            _ => PollingWatchKind::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
 /// These options make up the bulk of TypeScript’s configuration and it covers how the language should work.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CompilerOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_js: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub check_js: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub composite: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub downlevel_iteration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub import_helpers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub incremental: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub isolated_modules: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolated_declarations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim_module_syntax: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub erasable_syntax_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx: Option<Jsx>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lib: Option<Vec<Lib>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lib_replacement: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_detection: Option<ModuleDetection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub module: Option<Module>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remove_comments: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<Target>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ts_build_info_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub always_strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_any: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_this: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_bind_call_apply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_function_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_null_checks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strict_property_initialization: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_synthetic_default_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_umd_global_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub base_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub es_module_interop: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub module_resolution: Option<ModuleResolutionMode>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_paths"
+    )]
     pub paths: Option<HashMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_symlinks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dirs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub type_roots: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_arbitrary_extensions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_importing_ts_extensions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_conditions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_suffixes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unchecked_side_effect_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_package_json_exports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolve_package_json_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite_relative_import_extensions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_source_map: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_sources: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub map_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_fallthrough_cases_in_switch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_returns: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_property_access_from_index_signature: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unchecked_indexed_access: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unused_locals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_parameters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact_optional_property_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_implicit_override: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_unknown_in_catch_variables: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict_builtin_iterator_return: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emit_decorator_metadata: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental_decorators: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_unreachable_code: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_unused_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub assume_changes_only_affect_direct_dependencies: Option<bool>,
     #[deprecated]
-    pub charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charset: Option<Charset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub declaration_dir: Option<String>,
     #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub diagnostics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_referenced_project_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_size_limit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_solution_searching: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_source_of_project_reference_redirect: Option<bool>,
-    #[serde(rename = "emitBOM")]
+    #[serde(rename = "emitBOM", skip_serializing_if = "Option::is_none")]
     pub emit_bom: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emit_declaration_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_line: Option<NewLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub explain_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_diagnostics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub force_consistent_casing_in_file_names: Option<bool>,
     // XXX: Is generateCpuProfile available from tsconfig? Or just the CLI?
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub generate_cpu_profile: Option<bool>,
-
-    pub imports_not_used_as_values: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_trace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_check: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imports_not_used_as_values: Option<ImportsNotUsedAsValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_fragment_factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jsx_import_source: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub keyof_strings_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub list_emitted_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub list_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_node_module_js_depth: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit_helpers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_emit_on_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_error_truncation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_implicit_use_strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_lib: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_resolve: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub no_strict_generic_checks: Option<bool>,
     #[deprecated]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub out: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_const_enums: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub react_namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resolve_json_module: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_default_lib_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_lib_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub strip_internal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suppress_excess_property_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suppress_implicit_any_index_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_resolution: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub use_define_for_class_fields: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preserve_watch_output: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretty: Option<bool>,
-    pub fallback_polling: Option<String>,
-    pub watch_directory: Option<String>,
-    pub watch_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_deprecations: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_value_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_build_on_errors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<Vec<Plugin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_polling: Option<PollingWatchKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_directory: Option<WatchDirectoryKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_file: Option<WatchFileKind>,
+    /// Options not described above, which tsc would reject as unknown unless a newer version
+    /// added them.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// The end of line sequence to use when emitting files.
+#[derive(Debug, PartialEq, Clone)]
+pub enum NewLine {
+    /// `\r\n`, as on Windows.
+    Crlf,
+    /// `\n`, as on Unix.
+    Lf,
+    Other(String),
+}
+
+impl NewLine {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            NewLine::Crlf => "crlf",
+            NewLine::Lf => "lf",
+            NewLine::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for NewLine {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "CRLF" => NewLine::Crlf,
+            "LF" => NewLine::Lf,
+            _ => NewLine::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// How TypeScript decides whether a file is a script or a module.
+///
+/// * `auto`: a file is a module if it has imports or exports, or if `"type": "module"` applies to
+///   it with the `node16` and `nodenext` modules, or if it is a JSX file with a `react-jsx`
+///   transform.
+/// * `legacy`: a file is a module if it has imports or exports, as before TypeScript 4.7.
+/// * `force`: every non-declaration file is a module.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ModuleDetection {
+    Auto,
+    Legacy,
+    Force,
+    Other(String),
+}
+
+impl ModuleDetection {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModuleDetection::Auto => "auto",
+            ModuleDetection::Legacy => "legacy",
+            ModuleDetection::Force => "force",
+            ModuleDetection::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for ModuleDetection {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "AUTO" => ModuleDetection::Auto,
+            "LEGACY" => ModuleDetection::Legacy,
+            "FORCE" => ModuleDetection::Force,
+            _ => ModuleDetection::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// What happens to imports of which only types are used, the predecessor of
+/// `verbatimModuleSyntax`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ImportsNotUsedAsValues {
+    /// Drop the import statement, the default.
+    Remove,
+    /// Keep the import statement, for its side effects.
+    Preserve,
+    /// Keep the import statement, and report an error for it.
+    Error,
+    Other(String),
+}
+
+impl ImportsNotUsedAsValues {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ImportsNotUsedAsValues::Remove => "remove",
+            ImportsNotUsedAsValues::Preserve => "preserve",
+            ImportsNotUsedAsValues::Error => "error",
+            ImportsNotUsedAsValues::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for ImportsNotUsedAsValues {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "REMOVE" => ImportsNotUsedAsValues::Remove,
+            "PRESERVE" => ImportsNotUsedAsValues::Preserve,
+            "ERROR" => ImportsNotUsedAsValues::Error,
+            _ => ImportsNotUsedAsValues::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// The character encoding of input files.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Charset {
+    /// UTF-8, the default.
+    Utf8,
+    Other(String),
+}
+
+impl Charset {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Charset::Utf8 => "utf8",
+            Charset::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for Charset {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s.to_uppercase().as_str() {
+            "UTF8" => Charset::Utf8,
+            "UTF-8" => Charset::Utf8,
+            _ => Charset::Other(s.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// A language service plugin to load in editors, as listed in `compilerOptions.plugins`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Plugin {
+    /// The package name of the plugin.
+    pub name: String,
+    /// The plugin's own settings.
+    #[serde(flatten)]
+    pub options: BTreeMap<String, Value>,
 }
 
 /// Module resolution mode
 ///
 /// Specify the module resolution strategy: 'node' (Node.js) or 'classic' (used in TypeScript before the release of 1.6). You probably won’t need to use classic in modern code.
 /// There is a handbook reference page [on Module Resolution](https://www.typescriptlang.org/docs/handbook/module-resolution.html).
-#[derive(Deserialize, Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub enum ModuleResolutionMode {
-    #[serde(rename = "node")]
     Node,
-    #[serde(rename = "node10")]
     Node10,
-    #[serde(rename = "node16")]
     Node16,
-    #[serde(rename = "nodenext")]
     NodeNext,
-    #[serde(rename = "classic")]
     Classic,
-    #[serde(rename = "bundler")]
     Bundler,
 }
 
+impl ModuleResolutionMode {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModuleResolutionMode::Node => "node",
+            ModuleResolutionMode::Node10 => "node10",
+            ModuleResolutionMode::Node16 => "node16",
+            ModuleResolutionMode::NodeNext => "nodenext",
+            ModuleResolutionMode::Classic => "classic",
+            ModuleResolutionMode::Bundler => "bundler",
+        }
+    }
+}
+
+impl FromStr for ModuleResolutionMode {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "NODE" => Ok(ModuleResolutionMode::Node),
+            "NODE10" => Ok(ModuleResolutionMode::Node10),
+            "NODE16" => Ok(ModuleResolutionMode::Node16),
+            "NODENEXT" => Ok(ModuleResolutionMode::NodeNext),
+            "CLASSIC" => Ok(ModuleResolutionMode::Classic),
+            "BUNDLER" => Ok(ModuleResolutionMode::Bundler),
+            _ => Err(ParseOptionError::new(
+                s,
+                &["node", "node10", "node16", "nodenext", "classic", "bundler"],
+            )),
+        }
+    }
+}
+
 /// Controls how JSX constructs are emitted in JavaScript files. This only affects output of JS files that started in .tsx files.
 ///
 ///
@@ -514,8 +1383,7 @@ pub enum ModuleResolutionMode {
 /// const _jsxFileName = "/home/runner/work/TypeScript-Website/TypeScript-Website/packages/typescriptlang-org/index.tsx";
 /// export const helloWorld = () => _jsxDEV("h1", { children: "Hello world" }, void 0, false, { fileName: _jsxFileName, lineNumber: 7, columnNumber: 32 }, this);
 /// ```
-#[derive(Deserialize, Debug, PartialEq, Copy, Clone)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Jsx {
     /// Emit .js files with JSX changed to the equivalent React.createElement calls
     React,
@@ -523,12 +1391,49 @@ pub enum Jsx {
     ReactJsx,
     /// Emit .js files with the JSX to _jsx calls
     ReactJsxdev,
-    /// Emit .js files with the JSX unchanged
+    /// Emit .jsx files with the JSX unchanged
     ReactNative,
     /// Emit .jsx files with the JSX unchanged
     Preserve,
 }
 
+impl Jsx {
+    /// The canonical spelling of the value, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Jsx::React => "react",
+            Jsx::ReactJsx => "react-jsx",
+            Jsx::ReactJsxdev => "react-jsxdev",
+            Jsx::ReactNative => "react-native",
+            Jsx::Preserve => "preserve",
+        }
+    }
+}
+
+impl FromStr for Jsx {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "REACT" => Ok(Jsx::React),
+            "REACT-JSX" => Ok(Jsx::ReactJsx),
+            "REACT-JSXDEV" => Ok(Jsx::ReactJsxdev),
+            "REACT-NATIVE" => Ok(Jsx::ReactNative),
+            "PRESERVE" => Ok(Jsx::Preserve),
+            _ => Err(ParseOptionError::new(
+                s,
+                &[
+                    "react",
+                    "react-jsx",
+                    "react-jsxdev",
+                    "react-native",
+                    "preserve",
+                ],
+            )),
+        }
+    }
+}
+
 /// The transpilation target for the emitted JavaScript.
 ///
 /// Modern browsers support all `ES6` features, so `ES6` is a good choice. You might choose to set a lower target if your code
@@ -546,7 +1451,18 @@ pub enum Jsx {
 /// The special `ESNext` value refers to the highest version your version of TypeScript supports. This setting should be
 /// used with caution, since it doesn’t mean the same thing between different TypeScript versions and can
 /// make upgrades less predictable.
-#[derive(Debug, PartialEq, Clone)]
+///
+/// Targets compare by the edition of ECMAScript they stand for, so `ES6` equals `ES2015` and
+/// `ESNext` is above every other target. Unknown targets only compare to themselves.
+///
+/// ## Example
+/// ```
+/// use tsconfig::Target;
+///
+/// assert!(Target::Es2020 >= Target::Es2017);
+/// assert_eq!(Target::Es6, Target::Es2015);
+/// ```
+#[derive(Debug, Clone)]
 pub enum Target {
     Es3,
     Es5,
@@ -558,18 +1474,119 @@ pub enum Target {
     Es2018,
     Es2019,
     Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    Es2024,
     EsNext,
     Other(String),
 }
-impl<'de> Deserialize<'de> for Target {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
+impl Target {
+    /// The canonical spelling of the target, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Target::Es3 => "ES3",
+            Target::Es5 => "ES5",
+            Target::Es2015 => "ES2015",
+            Target::Es6 => "ES6",
+            Target::Es2016 => "ES2016",
+            Target::Es7 => "ES7",
+            Target::Es2017 => "ES2017",
+            Target::Es2018 => "ES2018",
+            Target::Es2019 => "ES2019",
+            Target::Es2020 => "ES2020",
+            Target::Es2021 => "ES2021",
+            Target::Es2022 => "ES2022",
+            Target::Es2023 => "ES2023",
+            Target::Es2024 => "ES2024",
+            Target::EsNext => "ESNext",
+            Target::Other(other) => other,
+        }
+    }
+
+    /// The libraries tsc includes when `lib` is not set: those of the target, along with the DOM
+    /// and the Windows Script Host.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{Lib, Target};
+    ///
+    /// assert_eq!(Target::Es5.default_lib()[..2], [Lib::Es5, Lib::Dom]);
+    /// ```
+    pub fn default_lib(&self) -> Vec<Lib> {
+        let mut lib = vec![match self {
+            Target::Es3 | Target::Es5 | Target::Other(_) => Lib::Es5,
+            Target::Es2015 | Target::Es6 => Lib::Es2015,
+            Target::Es2016 | Target::Es7 => Lib::Es2016,
+            Target::Es2017 => Lib::Es2017,
+            Target::Es2018 => Lib::Es2018,
+            Target::Es2019 => Lib::Es2019,
+            Target::Es2020 => Lib::Es2020,
+            Target::Es2021 => Lib::Es2021,
+            Target::Es2022 => Lib::Es2022,
+            Target::Es2023 => Lib::Es2023,
+            Target::Es2024 => Lib::Es2024,
+            Target::EsNext => Lib::EsNext,
+        }];
+        lib.push(Lib::Dom);
+        if *self >= Target::Es2015 {
+            lib.push(Lib::DomIterable);
+        }
+        if *self >= Target::Es2018 {
+            lib.push(Lib::DomAsyncIterable);
+        }
+        lib.push(Lib::WebWorkerImportScripts);
+        lib.push(Lib::ScriptHost);
+        lib
+    }
+
+    /// The position of the target in the order of ECMAScript editions, `None` for unknown targets.
+    fn rank(&self) -> Option<u32> {
+        let rank = match self {
+            Target::Es3 => 3,
+            Target::Es5 => 5,
+            Target::Es2015 | Target::Es6 => 2015,
+            Target::Es2016 | Target::Es7 => 2016,
+            Target::Es2017 => 2017,
+            Target::Es2018 => 2018,
+            Target::Es2019 => 2019,
+            Target::Es2020 => 2020,
+            Target::Es2021 => 2021,
+            Target::Es2022 => 2022,
+            Target::Es2023 => 2023,
+            Target::Es2024 => 2024,
+            Target::EsNext => u32::MAX,
+            Target::Other(_) => return None,
+        };
+        Some(rank)
+    }
+}
+
+impl PartialEq for Target {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Target::Other(a), Target::Other(b)) => a == b,
+            _ => self.rank().is_some() && self.rank() == other.rank(),
+        }
+    }
+}
+
+impl PartialOrd for Target {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.rank(), other.rank()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ if self == other => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = Infallible;
 
-        let d = match s.as_str() {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let d = match s.to_uppercase().as_str() {
+            "ES3" => Target::Es3,
             "ES5" => Target::Es5,
             "ES2015" => Target::Es2015,
             "ES6" => Target::Es6,
@@ -579,8 +1596,12 @@ impl<'de> Deserialize<'de> for Target {
             "ES2018" => Target::Es2018,
             "ES2019" => Target::Es2019,
             "ES2020" => Target::Es2020,
+            "ES2021" => Target::Es2021,
+            "ES2022" => Target::Es2022,
+            "ES2023" => Target::Es2023,
+            "ES2024" => Target::Es2024,
             "ESNEXT" => Target::EsNext,
-            other => Target::Other(other.to_string()),
+            _ => Target::Other(s.to_string()),
         };
 
         Ok(d)
@@ -600,20 +1621,29 @@ impl<'de> Deserialize<'de> for Target {
 #[derive(Debug, PartialEq, Clone)]
 pub enum Lib {
     Es5,
-    Es2015,
     Es6,
-    Es2016,
+    Es2015,
     Es7,
+    Es2016,
     Es2017,
     Es2018,
     Es2019,
     Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    Es2024,
     EsNext,
     Dom,
+    DomIterable,
+    DomAsyncIterable,
     WebWorker,
+    WebWorkerImportScripts,
+    WebWorkerIterable,
+    WebWorkerAsyncIterable,
     ScriptHost,
-    DomIterable,
     Es2015Core,
+    Es2015Collection,
     Es2015Generator,
     Es2015Iterable,
     Es2015Promise,
@@ -622,11 +1652,16 @@ pub enum Lib {
     Es2015Symbol,
     Es2015SymbolWellKnown,
     Es2016ArrayInclude,
+    Es2016Intl,
+    Es2017ArrayBuffer,
+    Es2017Date,
     Es2017Object,
-    Es2017Intl,
     Es2017SharedMemory,
     Es2017String,
+    Es2017Intl,
     Es2017TypedArrays,
+    Es2018AsyncGenerator,
+    Es2018AsyncIterable,
     Es2018Intl,
     Es2018Promise,
     Es2018RegExp,
@@ -634,52 +1669,472 @@ pub enum Lib {
     Es2019Object,
     Es2019String,
     Es2019Symbol,
+    Es2019Intl,
+    Es2020BigInt,
+    Es2020Date,
+    Es2020Promise,
+    Es2020SharedMemory,
     Es2020String,
     Es2020SymbolWellknown,
-    EsNextAsyncIterable,
+    Es2020Intl,
+    Es2020Number,
+    Es2021Promise,
+    Es2021String,
+    Es2021WeakRef,
+    Es2021Intl,
+    Es2022Array,
+    Es2022Error,
+    Es2022Intl,
+    Es2022Object,
+    Es2022RegExp,
+    Es2022String,
+    Es2023Array,
+    Es2023Collection,
+    Es2023Intl,
+    Es2024ArrayBuffer,
+    Es2024Collection,
+    Es2024Object,
+    Es2024Promise,
+    Es2024RegExp,
+    Es2024SharedMemory,
+    Es2024String,
     EsNextArray,
-    EsNextIntl,
+    EsNextCollection,
     EsNextSymbol,
+    EsNextAsyncIterable,
+    EsNextIntl,
+    EsNextDisposable,
+    EsNextBigInt,
+    EsNextString,
+    EsNextPromise,
+    EsNextWeakRef,
+    EsNextDecorators,
+    EsNextObject,
+    EsNextRegExp,
+    EsNextIterator,
+    Decorators,
+    DecoratorsLegacy,
     Other(String),
 }
 
-impl<'de> Deserialize<'de> for Lib {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
+impl Lib {
+    /// The canonical spelling of the library, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Lib::Es5 => "es5",
+            Lib::Es6 => "es6",
+            Lib::Es2015 => "es2015",
+            Lib::Es7 => "es7",
+            Lib::Es2016 => "es2016",
+            Lib::Es2017 => "es2017",
+            Lib::Es2018 => "es2018",
+            Lib::Es2019 => "es2019",
+            Lib::Es2020 => "es2020",
+            Lib::Es2021 => "es2021",
+            Lib::Es2022 => "es2022",
+            Lib::Es2023 => "es2023",
+            Lib::Es2024 => "es2024",
+            Lib::EsNext => "esnext",
+            Lib::Dom => "dom",
+            Lib::DomIterable => "dom.iterable",
+            Lib::DomAsyncIterable => "dom.asynciterable",
+            Lib::WebWorker => "webworker",
+            Lib::WebWorkerImportScripts => "webworker.importscripts",
+            Lib::WebWorkerIterable => "webworker.iterable",
+            Lib::WebWorkerAsyncIterable => "webworker.asynciterable",
+            Lib::ScriptHost => "scripthost",
+            Lib::Es2015Core => "es2015.core",
+            Lib::Es2015Collection => "es2015.collection",
+            Lib::Es2015Generator => "es2015.generator",
+            Lib::Es2015Iterable => "es2015.iterable",
+            Lib::Es2015Promise => "es2015.promise",
+            Lib::Es2015Proxy => "es2015.proxy",
+            Lib::Es2015Reflect => "es2015.reflect",
+            Lib::Es2015Symbol => "es2015.symbol",
+            Lib::Es2015SymbolWellKnown => "es2015.symbol.wellknown",
+            Lib::Es2016ArrayInclude => "es2016.array.include",
+            Lib::Es2016Intl => "es2016.intl",
+            Lib::Es2017ArrayBuffer => "es2017.arraybuffer",
+            Lib::Es2017Date => "es2017.date",
+            Lib::Es2017Object => "es2017.object",
+            Lib::Es2017SharedMemory => "es2017.sharedmemory",
+            Lib::Es2017String => "es2017.string",
+            Lib::Es2017Intl => "es2017.intl",
+            Lib::Es2017TypedArrays => "es2017.typedarrays",
+            Lib::Es2018AsyncGenerator => "es2018.asyncgenerator",
+            Lib::Es2018AsyncIterable => "es2018.asynciterable",
+            Lib::Es2018Intl => "es2018.intl",
+            Lib::Es2018Promise => "es2018.promise",
+            Lib::Es2018RegExp => "es2018.regexp",
+            Lib::Es2019Array => "es2019.array",
+            Lib::Es2019Object => "es2019.object",
+            Lib::Es2019String => "es2019.string",
+            Lib::Es2019Symbol => "es2019.symbol",
+            Lib::Es2019Intl => "es2019.intl",
+            Lib::Es2020BigInt => "es2020.bigint",
+            Lib::Es2020Date => "es2020.date",
+            Lib::Es2020Promise => "es2020.promise",
+            Lib::Es2020SharedMemory => "es2020.sharedmemory",
+            Lib::Es2020String => "es2020.string",
+            Lib::Es2020SymbolWellknown => "es2020.symbol.wellknown",
+            Lib::Es2020Intl => "es2020.intl",
+            Lib::Es2020Number => "es2020.number",
+            Lib::Es2021Promise => "es2021.promise",
+            Lib::Es2021String => "es2021.string",
+            Lib::Es2021WeakRef => "es2021.weakref",
+            Lib::Es2021Intl => "es2021.intl",
+            Lib::Es2022Array => "es2022.array",
+            Lib::Es2022Error => "es2022.error",
+            Lib::Es2022Intl => "es2022.intl",
+            Lib::Es2022Object => "es2022.object",
+            Lib::Es2022RegExp => "es2022.regexp",
+            Lib::Es2022String => "es2022.string",
+            Lib::Es2023Array => "es2023.array",
+            Lib::Es2023Collection => "es2023.collection",
+            Lib::Es2023Intl => "es2023.intl",
+            Lib::Es2024ArrayBuffer => "es2024.arraybuffer",
+            Lib::Es2024Collection => "es2024.collection",
+            Lib::Es2024Object => "es2024.object",
+            Lib::Es2024Promise => "es2024.promise",
+            Lib::Es2024RegExp => "es2024.regexp",
+            Lib::Es2024SharedMemory => "es2024.sharedmemory",
+            Lib::Es2024String => "es2024.string",
+            Lib::EsNextArray => "esnext.array",
+            Lib::EsNextCollection => "esnext.collection",
+            Lib::EsNextSymbol => "esnext.symbol",
+            Lib::EsNextAsyncIterable => "esnext.asynciterable",
+            Lib::EsNextIntl => "esnext.intl",
+            Lib::EsNextDisposable => "esnext.disposable",
+            Lib::EsNextBigInt => "esnext.bigint",
+            Lib::EsNextString => "esnext.string",
+            Lib::EsNextPromise => "esnext.promise",
+            Lib::EsNextWeakRef => "esnext.weakref",
+            Lib::EsNextDecorators => "esnext.decorators",
+            Lib::EsNextObject => "esnext.object",
+            Lib::EsNextRegExp => "esnext.regexp",
+            Lib::EsNextIterator => "esnext.iterator",
+            Lib::Decorators => "decorators",
+            Lib::DecoratorsLegacy => "decorators.legacy",
+            Lib::Other(other) => other,
+        }
+    }
 
-        let d = match s.as_str() {
-            "ES5" => Lib::Es5,
-            "ES2015" => Lib::Es2015,
-            "ES6" => Lib::Es6,
-            "ES2016" => Lib::Es2016,
-            "ES7" => Lib::Es7,
-            "ES2017" => Lib::Es2017,
-            "ES2018" => Lib::Es2018,
-            "ES2019" => Lib::Es2019,
-            "ES2020" => Lib::Es2020,
-            "ESNext" => Lib::EsNext,
-            "DOM" => Lib::Dom,
-            "WEBWORKER" => Lib::WebWorker,
-            "SCRIPTHOST" => Lib::ScriptHost,
-            "DOM.ITERABLE" => Lib::DomIterable,
-            "ES2015.CORE" => Lib::Es2015Core,
-            "ES2015.GENERATOR" => Lib::Es2015Generator,
-            "ES2015.ITERABLE" => Lib::Es2015Iterable,
-            "ES2015.PROMISE" => Lib::Es2015Promise,
-            "ES2015.PROXY" => Lib::Es2015Proxy,
-            "ES2015.REFLECT" => Lib::Es2015Reflect,
-            "ES2015.SYMBOL" => Lib::Es2015Symbol,
-            "ES2015.SYMBOL.WELLKNOWN" => Lib::Es2015SymbolWellKnown,
-            "ES2015.ARRAY.INCLUDE" => Lib::Es2016ArrayInclude,
-            "ES2015.OBJECT" => Lib::Es2017Object,
-            "ES2017INTL" => Lib::Es2017Intl,
-            "ES2015.SHAREDMEMORY" => Lib::Es2017SharedMemory,
-            "ES2017.STRING" => Lib::Es2017String,
+    /// The file tsc loads for the library, `None` for unknown libraries.
+    ///
+    /// Aliases such as `es6` or `esnext.symbol` share the file of the library they stand for.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::Lib;
+    ///
+    /// assert_eq!(Lib::Es2015Core.file_name(), Some("lib.es2015.core.d.ts"));
+    /// assert_eq!(Lib::EsNextSymbol.file_name(), Some("lib.es2019.symbol.d.ts"));
+    /// ```
+    pub fn file_name(&self) -> Option<&'static str> {
+        let file_name = match self {
+            Lib::Es5 => "lib.es5.d.ts",
+            Lib::Es6 => "lib.es2015.d.ts",
+            Lib::Es2015 => "lib.es2015.d.ts",
+            Lib::Es7 => "lib.es2016.d.ts",
+            Lib::Es2016 => "lib.es2016.d.ts",
+            Lib::Es2017 => "lib.es2017.d.ts",
+            Lib::Es2018 => "lib.es2018.d.ts",
+            Lib::Es2019 => "lib.es2019.d.ts",
+            Lib::Es2020 => "lib.es2020.d.ts",
+            Lib::Es2021 => "lib.es2021.d.ts",
+            Lib::Es2022 => "lib.es2022.d.ts",
+            Lib::Es2023 => "lib.es2023.d.ts",
+            Lib::Es2024 => "lib.es2024.d.ts",
+            Lib::EsNext => "lib.esnext.d.ts",
+            Lib::Dom => "lib.dom.d.ts",
+            Lib::DomIterable => "lib.dom.iterable.d.ts",
+            Lib::DomAsyncIterable => "lib.dom.asynciterable.d.ts",
+            Lib::WebWorker => "lib.webworker.d.ts",
+            Lib::WebWorkerImportScripts => "lib.webworker.importscripts.d.ts",
+            Lib::WebWorkerIterable => "lib.webworker.iterable.d.ts",
+            Lib::WebWorkerAsyncIterable => "lib.webworker.asynciterable.d.ts",
+            Lib::ScriptHost => "lib.scripthost.d.ts",
+            Lib::Es2015Core => "lib.es2015.core.d.ts",
+            Lib::Es2015Collection => "lib.es2015.collection.d.ts",
+            Lib::Es2015Generator => "lib.es2015.generator.d.ts",
+            Lib::Es2015Iterable => "lib.es2015.iterable.d.ts",
+            Lib::Es2015Promise => "lib.es2015.promise.d.ts",
+            Lib::Es2015Proxy => "lib.es2015.proxy.d.ts",
+            Lib::Es2015Reflect => "lib.es2015.reflect.d.ts",
+            Lib::Es2015Symbol => "lib.es2015.symbol.d.ts",
+            Lib::Es2015SymbolWellKnown => "lib.es2015.symbol.wellknown.d.ts",
+            Lib::Es2016ArrayInclude => "lib.es2016.array.include.d.ts",
+            Lib::Es2016Intl => "lib.es2016.intl.d.ts",
+            Lib::Es2017ArrayBuffer => "lib.es2017.arraybuffer.d.ts",
+            Lib::Es2017Date => "lib.es2017.date.d.ts",
+            Lib::Es2017Object => "lib.es2017.object.d.ts",
+            Lib::Es2017SharedMemory => "lib.es2017.sharedmemory.d.ts",
+            Lib::Es2017String => "lib.es2017.string.d.ts",
+            Lib::Es2017Intl => "lib.es2017.intl.d.ts",
+            Lib::Es2017TypedArrays => "lib.es2017.typedarrays.d.ts",
+            Lib::Es2018AsyncGenerator => "lib.es2018.asyncgenerator.d.ts",
+            Lib::Es2018AsyncIterable => "lib.es2018.asynciterable.d.ts",
+            Lib::Es2018Intl => "lib.es2018.intl.d.ts",
+            Lib::Es2018Promise => "lib.es2018.promise.d.ts",
+            Lib::Es2018RegExp => "lib.es2018.regexp.d.ts",
+            Lib::Es2019Array => "lib.es2019.array.d.ts",
+            Lib::Es2019Object => "lib.es2019.object.d.ts",
+            Lib::Es2019String => "lib.es2019.string.d.ts",
+            Lib::Es2019Symbol => "lib.es2019.symbol.d.ts",
+            Lib::Es2019Intl => "lib.es2019.intl.d.ts",
+            Lib::Es2020BigInt => "lib.es2020.bigint.d.ts",
+            Lib::Es2020Date => "lib.es2020.date.d.ts",
+            Lib::Es2020Promise => "lib.es2020.promise.d.ts",
+            Lib::Es2020SharedMemory => "lib.es2020.sharedmemory.d.ts",
+            Lib::Es2020String => "lib.es2020.string.d.ts",
+            Lib::Es2020SymbolWellknown => "lib.es2020.symbol.wellknown.d.ts",
+            Lib::Es2020Intl => "lib.es2020.intl.d.ts",
+            Lib::Es2020Number => "lib.es2020.number.d.ts",
+            Lib::Es2021Promise => "lib.es2021.promise.d.ts",
+            Lib::Es2021String => "lib.es2021.string.d.ts",
+            Lib::Es2021WeakRef => "lib.es2021.weakref.d.ts",
+            Lib::Es2021Intl => "lib.es2021.intl.d.ts",
+            Lib::Es2022Array => "lib.es2022.array.d.ts",
+            Lib::Es2022Error => "lib.es2022.error.d.ts",
+            Lib::Es2022Intl => "lib.es2022.intl.d.ts",
+            Lib::Es2022Object => "lib.es2022.object.d.ts",
+            Lib::Es2022RegExp => "lib.es2022.regexp.d.ts",
+            Lib::Es2022String => "lib.es2022.string.d.ts",
+            Lib::Es2023Array => "lib.es2023.array.d.ts",
+            Lib::Es2023Collection => "lib.es2023.collection.d.ts",
+            Lib::Es2023Intl => "lib.es2023.intl.d.ts",
+            Lib::Es2024ArrayBuffer => "lib.es2024.arraybuffer.d.ts",
+            Lib::Es2024Collection => "lib.es2024.collection.d.ts",
+            Lib::Es2024Object => "lib.es2024.object.d.ts",
+            Lib::Es2024Promise => "lib.es2024.promise.d.ts",
+            Lib::Es2024RegExp => "lib.es2024.regexp.d.ts",
+            Lib::Es2024SharedMemory => "lib.es2024.sharedmemory.d.ts",
+            Lib::Es2024String => "lib.es2024.string.d.ts",
+            Lib::EsNextArray => "lib.esnext.array.d.ts",
+            Lib::EsNextCollection => "lib.esnext.collection.d.ts",
+            Lib::EsNextSymbol => "lib.es2019.symbol.d.ts",
+            Lib::EsNextAsyncIterable => "lib.es2018.asynciterable.d.ts",
+            Lib::EsNextIntl => "lib.esnext.intl.d.ts",
+            Lib::EsNextDisposable => "lib.esnext.disposable.d.ts",
+            Lib::EsNextBigInt => "lib.es2020.bigint.d.ts",
+            Lib::EsNextString => "lib.es2024.string.d.ts",
+            Lib::EsNextPromise => "lib.es2024.promise.d.ts",
+            Lib::EsNextWeakRef => "lib.es2021.weakref.d.ts",
+            Lib::EsNextDecorators => "lib.esnext.decorators.d.ts",
+            Lib::EsNextObject => "lib.es2024.object.d.ts",
+            Lib::EsNextRegExp => "lib.es2024.regexp.d.ts",
+            Lib::EsNextIterator => "lib.esnext.iterator.d.ts",
+            Lib::Decorators => "lib.decorators.d.ts",
+            Lib::DecoratorsLegacy => "lib.decorators.legacy.d.ts",
+            Lib::Other(_) => return None,
+        };
+        Some(file_name)
+    }
+
+    /// The libraries the file of this one pulls in with `/// <reference lib="..." />`.
+    pub fn references(&self) -> Vec<Lib> {
+        match self {
+            Lib::Es5 => vec![Lib::Decorators, Lib::DecoratorsLegacy],
+            Lib::Es6 | Lib::Es2015 => vec![
+                Lib::Es5,
+                Lib::Es2015Core,
+                Lib::Es2015Collection,
+                Lib::Es2015Iterable,
+                Lib::Es2015Generator,
+                Lib::Es2015Promise,
+                Lib::Es2015Proxy,
+                Lib::Es2015Reflect,
+                Lib::Es2015Symbol,
+                Lib::Es2015SymbolWellKnown,
+            ],
+            Lib::Es7 | Lib::Es2016 => vec![Lib::Es2015, Lib::Es2016ArrayInclude, Lib::Es2016Intl],
+            Lib::Es2017 => vec![
+                Lib::Es2016,
+                Lib::Es2017ArrayBuffer,
+                Lib::Es2017Date,
+                Lib::Es2017Intl,
+                Lib::Es2017Object,
+                Lib::Es2017SharedMemory,
+                Lib::Es2017String,
+                Lib::Es2017TypedArrays,
+            ],
+            Lib::Es2018 => vec![
+                Lib::Es2017,
+                Lib::Es2018AsyncIterable,
+                Lib::Es2018AsyncGenerator,
+                Lib::Es2018Promise,
+                Lib::Es2018RegExp,
+                Lib::Es2018Intl,
+            ],
+            Lib::Es2019 => vec![
+                Lib::Es2018,
+                Lib::Es2019Array,
+                Lib::Es2019Object,
+                Lib::Es2019String,
+                Lib::Es2019Symbol,
+                Lib::Es2019Intl,
+            ],
+            Lib::Es2020 => vec![
+                Lib::Es2019,
+                Lib::Es2020BigInt,
+                Lib::Es2020Date,
+                Lib::Es2020Number,
+                Lib::Es2020Promise,
+                Lib::Es2020SharedMemory,
+                Lib::Es2020String,
+                Lib::Es2020SymbolWellknown,
+                Lib::Es2020Intl,
+            ],
+            Lib::Es2021 => vec![
+                Lib::Es2020,
+                Lib::Es2021Promise,
+                Lib::Es2021String,
+                Lib::Es2021WeakRef,
+                Lib::Es2021Intl,
+            ],
+            Lib::Es2022 => vec![
+                Lib::Es2021,
+                Lib::Es2022Array,
+                Lib::Es2022Error,
+                Lib::Es2022Intl,
+                Lib::Es2022Object,
+                Lib::Es2022RegExp,
+                Lib::Es2022String,
+            ],
+            Lib::Es2023 => vec![
+                Lib::Es2022,
+                Lib::Es2023Array,
+                Lib::Es2023Collection,
+                Lib::Es2023Intl,
+            ],
+            Lib::Es2024 => vec![
+                Lib::Es2023,
+                Lib::Es2024ArrayBuffer,
+                Lib::Es2024Collection,
+                Lib::Es2024Object,
+                Lib::Es2024Promise,
+                Lib::Es2024RegExp,
+                Lib::Es2024SharedMemory,
+                Lib::Es2024String,
+            ],
+            Lib::EsNext => vec![
+                Lib::Es2024,
+                Lib::EsNextIntl,
+                Lib::EsNextDecorators,
+                Lib::EsNextDisposable,
+                Lib::EsNextCollection,
+                Lib::EsNextArray,
+                Lib::EsNextIterator,
+            ],
+            Lib::Es2015Generator => vec![Lib::Es2015Iterable],
+            Lib::Es2015Iterable => vec![Lib::Es2015Symbol],
+            Lib::Es2015SymbolWellKnown => vec![Lib::Es2015Symbol],
+            Lib::Es2017SharedMemory => vec![Lib::Es2015Symbol, Lib::Es2015SymbolWellKnown],
+            Lib::Es2018AsyncGenerator => vec![Lib::Es2018AsyncIterable],
+            Lib::Es2018AsyncIterable | Lib::EsNextAsyncIterable => {
+                vec![Lib::Es2015Symbol, Lib::Es2015Iterable]
+            }
+            Lib::Es2019Object => vec![Lib::Es2015Iterable],
+            Lib::Es2020BigInt | Lib::EsNextBigInt => vec![Lib::Es2020Intl],
+            Lib::Es2020Date => vec![Lib::Es2020Intl],
+            Lib::Es2020SharedMemory => vec![Lib::Es2020BigInt],
+            Lib::Es2020String => vec![
+                Lib::Es2015Iterable,
+                Lib::Es2020Intl,
+                Lib::Es2020SymbolWellknown,
+            ],
+            Lib::Es2020SymbolWellknown => vec![Lib::Es2015Iterable, Lib::Es2015Symbol],
+            Lib::Es2020Intl => vec![Lib::Es2018Intl],
+            Lib::Es2020Number => vec![Lib::Es2020Intl],
+            Lib::Es2022Error => vec![Lib::Es2021Promise],
+            Lib::Es2024SharedMemory => vec![Lib::Es2020BigInt],
+            Lib::EsNextDisposable => vec![
+                Lib::Es2015Symbol,
+                Lib::Es2015Iterable,
+                Lib::Es2018AsyncIterable,
+            ],
+            Lib::EsNextDecorators => vec![Lib::Es2015Symbol, Lib::Decorators],
+            Lib::EsNextIterator => vec![Lib::Es2015Iterable],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Every library this one pulls in, directly or through the libraries it references, in the
+    /// order tsc first reaches them. Each file appears once, and the library itself is left out.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::Lib;
+    ///
+    /// let dependencies = Lib::Es2016.dependencies();
+    /// assert_eq!(dependencies[..2], [Lib::Es2015, Lib::Es5]);
+    /// assert!(dependencies.contains(&Lib::Es2015Symbol));
+    /// ```
+    pub fn dependencies(&self) -> Vec<Lib> {
+        let mut dependencies: Vec<Lib> = Vec::new();
+        let mut pending = self.references();
+        pending.reverse();
+        while let Some(lib) = pending.pop() {
+            let file_name = lib.file_name();
+            if file_name == self.file_name()
+                || dependencies.iter().any(|d| d.file_name() == file_name)
+            {
+                continue;
+            }
+            pending.extend(lib.references().into_iter().rev());
+            dependencies.push(lib);
+        }
+        dependencies
+    }
+}
+
+impl FromStr for Lib {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let d = match s.to_uppercase().as_str() {
+            "ES5" => Lib::Es5,
+            "ES6" => Lib::Es6,
+            "ES2015" => Lib::Es2015,
+            "ES7" => Lib::Es7,
+            "ES2016" => Lib::Es2016,
+            "ES2017" => Lib::Es2017,
+            "ES2018" => Lib::Es2018,
+            "ES2019" => Lib::Es2019,
+            "ES2020" => Lib::Es2020,
+            "ES2021" => Lib::Es2021,
+            "ES2022" => Lib::Es2022,
+            "ES2023" => Lib::Es2023,
+            "ES2024" => Lib::Es2024,
+            "ESNEXT" => Lib::EsNext,
+            "DOM" => Lib::Dom,
+            "DOM.ITERABLE" => Lib::DomIterable,
+            "DOM.ASYNCITERABLE" => Lib::DomAsyncIterable,
+            "WEBWORKER" => Lib::WebWorker,
+            "WEBWORKER.IMPORTSCRIPTS" => Lib::WebWorkerImportScripts,
+            "WEBWORKER.ITERABLE" => Lib::WebWorkerIterable,
+            "WEBWORKER.ASYNCITERABLE" => Lib::WebWorkerAsyncIterable,
+            "SCRIPTHOST" => Lib::ScriptHost,
+            "ES2015.CORE" => Lib::Es2015Core,
+            "ES2015.COLLECTION" => Lib::Es2015Collection,
+            "ES2015.GENERATOR" => Lib::Es2015Generator,
+            "ES2015.ITERABLE" => Lib::Es2015Iterable,
+            "ES2015.PROMISE" => Lib::Es2015Promise,
+            "ES2015.PROXY" => Lib::Es2015Proxy,
+            "ES2015.REFLECT" => Lib::Es2015Reflect,
+            "ES2015.SYMBOL" => Lib::Es2015Symbol,
+            "ES2015.SYMBOL.WELLKNOWN" => Lib::Es2015SymbolWellKnown,
+            "ES2016.ARRAY.INCLUDE" => Lib::Es2016ArrayInclude,
+            "ES2016.INTL" => Lib::Es2016Intl,
+            "ES2017.ARRAYBUFFER" => Lib::Es2017ArrayBuffer,
+            "ES2017.DATE" => Lib::Es2017Date,
+            "ES2017.OBJECT" => Lib::Es2017Object,
+            "ES2017.SHAREDMEMORY" => Lib::Es2017SharedMemory,
+            "ES2017.STRING" => Lib::Es2017String,
+            "ES2017.INTL" => Lib::Es2017Intl,
             "ES2017.TYPEDARRAYS" => Lib::Es2017TypedArrays,
+            "ES2018.ASYNCGENERATOR" => Lib::Es2018AsyncGenerator,
+            "ES2018.ASYNCITERABLE" => Lib::Es2018AsyncIterable,
             "ES2018.INTL" => Lib::Es2018Intl,
             "ES2018.PROMISE" => Lib::Es2018Promise,
             "ES2018.REGEXP" => Lib::Es2018RegExp,
@@ -687,13 +2142,52 @@ impl<'de> Deserialize<'de> for Lib {
             "ES2019.OBJECT" => Lib::Es2019Object,
             "ES2019.STRING" => Lib::Es2019String,
             "ES2019.SYMBOL" => Lib::Es2019Symbol,
+            "ES2019.INTL" => Lib::Es2019Intl,
+            "ES2020.BIGINT" => Lib::Es2020BigInt,
+            "ES2020.DATE" => Lib::Es2020Date,
+            "ES2020.PROMISE" => Lib::Es2020Promise,
+            "ES2020.SHAREDMEMORY" => Lib::Es2020SharedMemory,
             "ES2020.STRING" => Lib::Es2020String,
             "ES2020.SYMBOL.WELLKNOWN" => Lib::Es2020SymbolWellknown,
-            "ESNEXT.ASYNCITERABLE" => Lib::EsNextAsyncIterable,
+            "ES2020.INTL" => Lib::Es2020Intl,
+            "ES2020.NUMBER" => Lib::Es2020Number,
+            "ES2021.PROMISE" => Lib::Es2021Promise,
+            "ES2021.STRING" => Lib::Es2021String,
+            "ES2021.WEAKREF" => Lib::Es2021WeakRef,
+            "ES2021.INTL" => Lib::Es2021Intl,
+            "ES2022.ARRAY" => Lib::Es2022Array,
+            "ES2022.ERROR" => Lib::Es2022Error,
+            "ES2022.INTL" => Lib::Es2022Intl,
+            "ES2022.OBJECT" => Lib::Es2022Object,
+            "ES2022.REGEXP" => Lib::Es2022RegExp,
+            "ES2022.STRING" => Lib::Es2022String,
+            "ES2023.ARRAY" => Lib::Es2023Array,
+            "ES2023.COLLECTION" => Lib::Es2023Collection,
+            "ES2023.INTL" => Lib::Es2023Intl,
+            "ES2024.ARRAYBUFFER" => Lib::Es2024ArrayBuffer,
+            "ES2024.COLLECTION" => Lib::Es2024Collection,
+            "ES2024.OBJECT" => Lib::Es2024Object,
+            "ES2024.PROMISE" => Lib::Es2024Promise,
+            "ES2024.REGEXP" => Lib::Es2024RegExp,
+            "ES2024.SHAREDMEMORY" => Lib::Es2024SharedMemory,
+            "ES2024.STRING" => Lib::Es2024String,
             "ESNEXT.ARRAY" => Lib::EsNextArray,
-            "ESNEXT.INTL" => Lib::EsNextIntl,
+            "ESNEXT.COLLECTION" => Lib::EsNextCollection,
             "ESNEXT.SYMBOL" => Lib::EsNextSymbol,
-            other => Lib::Other(other.to_string()),
+            "ESNEXT.ASYNCITERABLE" => Lib::EsNextAsyncIterable,
+            "ESNEXT.INTL" => Lib::EsNextIntl,
+            "ESNEXT.DISPOSABLE" => Lib::EsNextDisposable,
+            "ESNEXT.BIGINT" => Lib::EsNextBigInt,
+            "ESNEXT.STRING" => Lib::EsNextString,
+            "ESNEXT.PROMISE" => Lib::EsNextPromise,
+            "ESNEXT.WEAKREF" => Lib::EsNextWeakRef,
+            "ESNEXT.DECORATORS" => Lib::EsNextDecorators,
+            "ESNEXT.OBJECT" => Lib::EsNextObject,
+            "ESNEXT.REGEXP" => Lib::EsNextRegExp,
+            "ESNEXT.ITERATOR" => Lib::EsNextIterator,
+            "DECORATORS" => Lib::Decorators,
+            "DECORATORS.LEGACY" => Lib::DecoratorsLegacy,
+            _ => Lib::Other(s.to_string()),
         };
 
         Ok(d)
@@ -801,45 +2295,244 @@ impl<'de> Deserialize<'de> for Lib {
 /// const constants_1 = require("./constants");
 /// exports.twoPi = constants_1.valueOfPi * 2;
 /// ```
+///
+/// ## Node16, Node18 and NodeNext
+///
+/// Each file is emitted as an ES module or as CommonJS depending on its format, as Node.js
+/// decides it from the file extension and the `type` of the nearest package.json.
+///
+/// ## Preserve
+///
+/// Imports and exports are emitted as written, so ES module syntax and `import x = require()` can
+/// live side by side, as bundlers understand them.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Module {
     CommonJs,
     Es6,
     Es2015,
     Es2020,
+    Es2022,
     None,
     Umd,
     Amd,
     System,
     EsNext,
+    Node16,
+    Node18,
+    NodeNext,
+    Preserve,
     Other(String),
 }
 
-impl<'de> Deserialize<'de> for Module {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        let s = s.to_uppercase();
+impl Module {
+    /// The canonical spelling of the module system, as written in a .tsconfig file.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Module::CommonJs => "CommonJS",
+            Module::Es6 => "ES6",
+            Module::Es2015 => "ES2015",
+            Module::Es2020 => "ES2020",
+            Module::Es2022 => "ES2022",
+            Module::None => "None",
+            Module::Umd => "UMD",
+            Module::Amd => "AMD",
+            Module::System => "System",
+            Module::EsNext => "ESNext",
+            Module::Node16 => "Node16",
+            Module::Node18 => "Node18",
+            Module::NodeNext => "NodeNext",
+            Module::Preserve => "Preserve",
+            Module::Other(other) => other,
+        }
+    }
+
+    /// The module resolution tsc uses with this module system when `moduleResolution` is not set.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{Module, ModuleResolutionMode};
+    ///
+    /// assert_eq!(Module::NodeNext.implied_module_resolution(), ModuleResolutionMode::NodeNext);
+    /// assert_eq!(Module::Preserve.implied_module_resolution(), ModuleResolutionMode::Bundler);
+    /// ```
+    pub fn implied_module_resolution(&self) -> ModuleResolutionMode {
+        match self {
+            Module::CommonJs => ModuleResolutionMode::Node10,
+            Module::Node16 | Module::Node18 => ModuleResolutionMode::Node16,
+            Module::NodeNext => ModuleResolutionMode::NodeNext,
+            Module::Preserve => ModuleResolutionMode::Bundler,
+            _ => ModuleResolutionMode::Classic,
+        }
+    }
+
+    /// Whether the module system follows the format of each file, ES module or CommonJS, as
+    /// Node.js does.
+    pub fn is_node(&self) -> bool {
+        matches!(self, Module::Node16 | Module::Node18 | Module::NodeNext)
+    }
 
-        let r = match s.as_str() {
+    /// Whether tsc emits `import` and `export` statements, rather than another module system, for
+    /// a file of the given format.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{FileFormat, Module};
+    ///
+    /// assert!(Module::EsNext.emits_esm_syntax_for(FileFormat::CommonJs));
+    /// assert!(!Module::NodeNext.emits_esm_syntax_for(FileFormat::CommonJs));
+    /// assert!(Module::NodeNext.emits_esm_syntax_for(FileFormat::EsModule));
+    /// ```
+    pub fn emits_esm_syntax_for(&self, format: FileFormat) -> bool {
+        match self {
+            Module::Es6 | Module::Es2015 | Module::Es2020 | Module::Es2022 | Module::EsNext => true,
+            Module::Node16 | Module::Node18 | Module::NodeNext | Module::Preserve => {
+                format == FileFormat::EsModule
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Module {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let r = match s.to_uppercase().as_str() {
             "COMMONJS" => Module::CommonJs,
-            "ESNEXT" => Module::EsNext,
             "ES6" => Module::Es6,
             "ES2015" => Module::Es2015,
             "ES2020" => Module::Es2020,
+            "ES2022" => Module::Es2022,
             "NONE" => Module::None,
             "UMD" => Module::Umd,
             "AMD" => Module::Amd,
             "SYSTEM" => Module::System,
-            other => Module::Other(other.to_string()),
+            "ESNEXT" => Module::EsNext,
+            "NODE16" => Module::Node16,
+            "NODE18" => Module::Node18,
+            "NODENEXT" => Module::NodeNext,
+            "PRESERVE" => Module::Preserve,
+            _ => Module::Other(s.to_string()),
         };
 
         Ok(r)
     }
 }
 
+/// The module format of an input file.
+///
+/// With the Node.js module systems, this is what Node.js decides from the file extension and the
+/// `type` of the nearest package.json. With `preserve`, it is the syntax the file is written in.
+/// It is spelled as that `type`: `commonjs` or `module`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FileFormat {
+    /// `.cjs` and `.cts` files, and `.js` and `.ts` files without `"type": "module"`.
+    CommonJs,
+    /// `.mjs` and `.mts` files, and `.js` and `.ts` files with `"type": "module"`.
+    EsModule,
+}
+
+impl FileFormat {
+    /// The canonical spelling of the value, as the `type` of a package.json.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FileFormat::CommonJs => "commonjs",
+            FileFormat::EsModule => "module",
+        }
+    }
+}
+
+impl FromStr for FileFormat {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "COMMONJS" => Ok(FileFormat::CommonJs),
+            "MODULE" => Ok(FileFormat::EsModule),
+            _ => Err(ParseOptionError::new(s, &["commonjs", "module"])),
+        }
+    }
+}
+
+/// The error returned when parsing a value that an option does not accept.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown value '{value}', expected one of: {}", .expected.join(", "))]
+pub struct ParseOptionError {
+    value: String,
+    expected: &'static [&'static str],
+}
+
+impl ParseOptionError {
+    fn new(value: &str, expected: &'static [&'static str]) -> Self {
+        ParseOptionError {
+            value: value.to_string(),
+            expected,
+        }
+    }
+}
+
+/// Implements `Display`, `Serialize` and `Deserialize` for enums of option values, through their
+/// `as_str` method and `FromStr` implementation, so that they read and write the same spellings
+/// in .tsconfig files and on the command line.
+macro_rules! option_value_enum {
+    ($($name:ident),* $(,)?) => {$(
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+option_value_enum!(
+    WatchFileKind,
+    WatchDirectoryKind,
+    PollingWatchKind,
+    NewLine,
+    ModuleDetection,
+    ImportsNotUsedAsValues,
+    Charset,
+    ModuleResolutionMode,
+    Jsx,
+    Target,
+    Lib,
+    Module,
+    FileFormat,
+);
+
+/// Writes `compilerOptions.paths` with its patterns sorted, so that output is deterministic.
+fn serialize_paths<S>(
+    paths: &Option<HashMap<String, Vec<String>>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let sorted = paths
+        .as_ref()
+        .map(|paths| paths.iter().collect::<std::collections::BTreeMap<_, _>>());
+    sorted.serialize(serializer)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -851,6 +2544,180 @@ mod test {
         assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactJsx));
     }
 
+    #[test]
+    fn parse_targets() {
+        for (json, target) in [
+            ("es3", Target::Es3),
+            ("ES2021", Target::Es2021),
+            ("es2022", Target::Es2022),
+            ("Es2023", Target::Es2023),
+            ("ES2024", Target::Es2024),
+            ("esnext", Target::EsNext),
+            ("ES2077", Target::Other("ES2077".to_string())),
+        ] {
+            let target_json = format!(r#"{{"compilerOptions": {{"target": "{}"}}}}"#, json);
+            let config = TsConfig::parse_str(&target_json).unwrap();
+            assert_eq!(config.compiler_options.unwrap().target, Some(target));
+        }
+    }
+
+    #[test]
+    fn orders_targets() {
+        assert!(Target::Es3 < Target::Es5);
+        assert!(Target::Es2017 >= Target::Es2017);
+        assert!(Target::Es6 < Target::Es7);
+        assert!(Target::Es2024 < Target::EsNext);
+        assert_eq!(Target::Es7, Target::Es2016);
+        assert_ne!(Target::Es2015, Target::Es2016);
+
+        let other = Target::Other("ES2077".to_string());
+        assert_eq!(other, other.clone());
+        assert_eq!(other.partial_cmp(&Target::Es5), None);
+    }
+
+    #[test]
+    fn default_lib_follows_target() {
+        assert_eq!(
+            Target::Es5.default_lib(),
+            [
+                Lib::Es5,
+                Lib::Dom,
+                Lib::WebWorkerImportScripts,
+                Lib::ScriptHost
+            ]
+        );
+        let lib = Target::Es2020.default_lib();
+        assert_eq!(lib[0], Lib::Es2020);
+        assert!(lib.contains(&Lib::DomIterable));
+        assert!(lib.contains(&Lib::DomAsyncIterable));
+        assert_eq!(Target::Es6.default_lib(), Target::Es2015.default_lib());
+    }
+
+    #[test]
+    fn parse_modules() {
+        for (json, module) in [
+            ("commonjs", Module::CommonJs),
+            ("es2022", Module::Es2022),
+            ("node16", Module::Node16),
+            ("Node18", Module::Node18),
+            ("NodeNext", Module::NodeNext),
+            ("preserve", Module::Preserve),
+            ("webpack", Module::Other("webpack".to_string())),
+        ] {
+            let module_json = format!(r#"{{"compilerOptions": {{"module": "{}"}}}}"#, json);
+            let config = TsConfig::parse_str(&module_json).unwrap();
+            assert_eq!(config.compiler_options.unwrap().module, Some(module));
+        }
+    }
+
+    #[test]
+    fn implies_module_resolution() {
+        assert_eq!(
+            Module::CommonJs.implied_module_resolution(),
+            ModuleResolutionMode::Node10
+        );
+        assert_eq!(
+            Module::Node18.implied_module_resolution(),
+            ModuleResolutionMode::Node16
+        );
+        assert_eq!(
+            Module::Es2022.implied_module_resolution(),
+            ModuleResolutionMode::Classic
+        );
+    }
+
+    #[test]
+    fn emits_esm_syntax_by_file_format() {
+        for format in [FileFormat::CommonJs, FileFormat::EsModule] {
+            assert!(Module::Es2015.emits_esm_syntax_for(format));
+            assert!(Module::Es2022.emits_esm_syntax_for(format));
+            assert!(!Module::CommonJs.emits_esm_syntax_for(format));
+            assert!(!Module::System.emits_esm_syntax_for(format));
+        }
+        for module in [Module::Node16, Module::NodeNext, Module::Preserve] {
+            assert!(module.emits_esm_syntax_for(FileFormat::EsModule));
+            assert!(!module.emits_esm_syntax_for(FileFormat::CommonJs));
+        }
+    }
+
+    #[test]
+    fn parse_libs() {
+        let json = r#"{"compilerOptions": {"lib": [
+            "ESNext", "es2016.Array.Include", "ES2017.Intl", "es2017.object", "dom.asyncIterable",
+            "webworker.importscripts", "esnext.disposable", "decorators.legacy", "es2099.magic"
+        ]}}"#;
+        let lib = TsConfig::parse_str(json)
+            .unwrap()
+            .compiler_options
+            .unwrap()
+            .lib
+            .unwrap();
+
+        assert_eq!(
+            lib,
+            [
+                Lib::EsNext,
+                Lib::Es2016ArrayInclude,
+                Lib::Es2017Intl,
+                Lib::Es2017Object,
+                Lib::DomAsyncIterable,
+                Lib::WebWorkerImportScripts,
+                Lib::EsNextDisposable,
+                Lib::DecoratorsLegacy,
+                Lib::Other("es2099.magic".to_string()),
+            ]
+        );
+        assert_eq!(
+            lib.iter().map(Lib::as_str).collect::<Vec<_>>(),
+            [
+                "esnext",
+                "es2016.array.include",
+                "es2017.intl",
+                "es2017.object",
+                "dom.asynciterable",
+                "webworker.importscripts",
+                "esnext.disposable",
+                "decorators.legacy",
+                "es2099.magic"
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_libs_to_files() {
+        assert_eq!(Lib::Es6.file_name(), Some("lib.es2015.d.ts"));
+        assert_eq!(Lib::Es2020.file_name(), Some("lib.es2020.d.ts"));
+        assert_eq!(
+            Lib::EsNextAsyncIterable.file_name(),
+            Some("lib.es2018.asynciterable.d.ts")
+        );
+        assert_eq!(Lib::Other("es2099".to_string()).file_name(), None);
+    }
+
+    #[test]
+    fn resolves_lib_dependencies() {
+        assert_eq!(
+            Lib::Es2015Generator.dependencies(),
+            [Lib::Es2015Iterable, Lib::Es2015Symbol]
+        );
+        assert_eq!(Lib::Es6.dependencies(), Lib::Es2015.dependencies());
+        assert!(Lib::Dom.dependencies().is_empty());
+
+        let dependencies = Lib::EsNext.dependencies();
+        assert_eq!(dependencies[0], Lib::Es2024);
+        for lib in [Lib::Es5, Lib::Decorators, Lib::Es2018AsyncIterable] {
+            assert_eq!(dependencies.iter().filter(|d| **d == lib).count(), 1);
+        }
+        // `es2015.symbol` is reached through several libraries but listed once.
+        assert_eq!(
+            dependencies
+                .iter()
+                .filter(|d| d.file_name() == Some("lib.es2015.symbol.d.ts"))
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn parse_paths() {
         let json = r#"{
@@ -898,29 +2765,249 @@ mod test {
     #[test]
     fn parse_complete_tsconfig() {
         let json = include_str!("../test/tsconfig.complete.json");
-        TsConfig::parse_str(json).unwrap();
+        let config = TsConfig::parse_str(json).unwrap();
+
+        let options = config.compiler_options.unwrap();
+        assert_eq!(options.no_unused_parameters, Some(true));
+        assert_eq!(options.exact_optional_property_types, Some(true));
+        assert_eq!(options.no_implicit_override, Some(true));
+        assert_eq!(options.use_unknown_in_catch_variables, Some(true));
+        assert_eq!(options.strict_builtin_iterator_return, Some(true));
+        assert_eq!(options.allow_arbitrary_extensions, Some(false));
+        assert_eq!(options.allow_importing_ts_extensions, Some(true));
+        assert_eq!(
+            options.custom_conditions,
+            Some(vec!["development".to_string()])
+        );
+        assert_eq!(
+            options.module_suffixes,
+            Some(vec![".ios".to_string(), "".to_string()])
+        );
+        assert_eq!(options.no_unchecked_side_effect_imports, Some(true));
+        assert_eq!(options.resolve_package_json_exports, Some(true));
+        assert_eq!(options.resolve_package_json_imports, Some(true));
+        assert_eq!(options.rewrite_relative_import_extensions, Some(false));
+        assert_eq!(options.new_line, Some(NewLine::Lf));
+        assert_eq!(options.verbatim_module_syntax, Some(false));
+        assert_eq!(options.isolated_declarations, Some(false));
+        assert_eq!(options.erasable_syntax_only, Some(false));
+        assert_eq!(options.lib_replacement, Some(false));
+        assert_eq!(options.module_detection, Some(ModuleDetection::Force));
+        assert_eq!(options.generate_trace, Some("./trace".to_string()));
+        assert_eq!(options.no_check, Some(false));
+        assert_eq!(options.stop_build_on_errors, Some(false));
+        assert_eq!(options.ignore_deprecations, Some("5.0".to_string()));
+        assert_eq!(options.preserve_value_imports, Some(false));
+        assert_eq!(
+            options.imports_not_used_as_values,
+            Some(ImportsNotUsedAsValues::Remove)
+        );
+
+        let plugins = options.plugins.unwrap();
+        assert_eq!(plugins[0].name, "typescript-styled-plugin");
+        assert_eq!(
+            plugins[0].options["tags"],
+            serde_json::json!(["styled", "css"])
+        );
+
+        let watch_options = config.watch_options.unwrap();
+        assert_eq!(
+            watch_options.watch_file,
+            Some(WatchFileKind::UseFsEventsOnParentDirectory)
+        );
+        assert_eq!(
+            watch_options.watch_directory,
+            Some(WatchDirectoryKind::DynamicPriorityPolling)
+        );
+        assert_eq!(
+            watch_options.fallback_polling,
+            Some(PollingWatchKind::FixedChunkSize)
+        );
+        assert_eq!(watch_options.synchronous_watch_directory, Some(false));
+        assert_eq!(
+            watch_options.exclude_directories,
+            Some(vec!["./node_modules".to_string()])
+        );
+        assert_eq!(
+            watch_options.exclude_files,
+            Some(vec!["./src/generated.ts".to_string()])
+        );
+
+        assert_eq!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Object {
+                enable: Some(true),
+                include: Some(vec!["jest".to_string()]),
+                exclude: Some(vec!["jquery".to_string()]),
+                disable_filename_based_type_acquisition: Some(true),
+            })
+        );
     }
 
     #[test]
-    fn ignores_invalid_fields() {
-        let json = r#"{"bleep": true, "compilerOptions": {"someNewUnsupportedProperty": false}}"#;
-        TsConfig::parse_str(json).unwrap();
+    fn parse_type_acquisition_forms() {
+        let config = TsConfig::parse_str(r#"{"typeAcquisition": false}"#).unwrap();
+        assert_eq!(config.type_acquisition, Some(TypeAcquisition::Bool(false)));
+
+        let config = TsConfig::parse_str(r#"{"typeAcquisition": {"include": ["jest"]}}"#).unwrap();
+        assert_eq!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Object {
+                enable: None,
+                include: Some(vec!["jest".to_string()]),
+                exclude: None,
+                disable_filename_based_type_acquisition: None,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({"typeAcquisition": {"include": ["jest"]}})
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_watch_strategies() {
+        let json = r#"{"watchOptions": {"watchFile": "inotify"}}"#;
+        let watch_options = TsConfig::parse_str(json).unwrap().watch_options.unwrap();
+        assert_eq!(
+            watch_options.watch_file,
+            Some(WatchFileKind::Other("inotify".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_legacy_string_options() {
+        let json = r#"{"compilerOptions": {
+            "importsNotUsedAsValues": "Preserve",
+            "charset": "UTF-8",
+            "watchFile": "UseFsEvents",
+            "watchDirectory": "fixedPollingInterval",
+            "fallbackPolling": "dynamicpriority",
+            "newLine": "crlf",
+            "moduleResolution": "Bundler"
+        }}"#;
+        let options = TsConfig::parse_str(json).unwrap().compiler_options.unwrap();
+
+        assert_eq!(
+            options.imports_not_used_as_values,
+            Some(ImportsNotUsedAsValues::Preserve)
+        );
+        #[allow(deprecated)]
+        let charset = options.charset;
+        assert_eq!(charset, Some(Charset::Utf8));
+        assert_eq!(options.watch_file, Some(WatchFileKind::UseFsEvents));
+        assert_eq!(
+            options.watch_directory,
+            Some(WatchDirectoryKind::FixedPollingInterval)
+        );
+        assert_eq!(
+            options.fallback_polling,
+            Some(PollingWatchKind::DynamicPriority)
+        );
+        assert_eq!(options.new_line, Some(NewLine::Crlf));
+        assert_eq!(
+            options.module_resolution,
+            Some(ModuleResolutionMode::Bundler)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_strict_option_values() {
+        assert!(TsConfig::parse_str(r#"{"compilerOptions": {"jsx": "vue"}}"#).is_err());
+        assert_eq!(
+            "node12".parse::<ModuleResolutionMode>().unwrap_err().to_string(),
+            "unknown value 'node12', expected one of: node, node10, node16, nodenext, classic, bundler"
+        );
+    }
+
+    #[test]
+    fn parses_and_displays_option_values() {
+        assert_eq!("esnext".parse(), Ok(Target::EsNext));
+        assert_eq!("DOM.Iterable".parse(), Ok(Lib::DomIterable));
+        assert_eq!("nodenext".parse(), Ok(Module::NodeNext));
+        assert_eq!("React-JSX".parse(), Ok(Jsx::ReactJsx));
+        assert_eq!("LF".parse(), Ok(NewLine::Lf));
+        assert_eq!(
+            "whatever".parse(),
+            Ok(ModuleDetection::Other("whatever".to_string()))
+        );
+
+        assert_eq!(Target::Es2022.to_string(), "ES2022");
+        assert_eq!(Lib::Es2017TypedArrays.to_string(), "es2017.typedarrays");
+        assert_eq!(Module::CommonJs.to_string(), "CommonJS");
+        assert_eq!(ModuleResolutionMode::NodeNext.to_string(), "nodenext");
+        assert_eq!(Jsx::ReactJsxdev.to_string(), "react-jsxdev");
+        assert_eq!(WatchFileKind::UseFsEvents.to_string(), "useFsEvents");
+        assert_eq!(
+            PollingWatchKind::FixedChunkSize.to_string(),
+            "fixedChunkSize"
+        );
+        assert_eq!(ImportsNotUsedAsValues::Error.to_string(), "error");
+        assert_eq!(Charset::Other("latin1".to_string()).to_string(), "latin1");
+
+        assert_eq!("Module".parse(), Ok(FileFormat::EsModule));
+        assert_eq!(FileFormat::CommonJs.to_string(), "commonjs");
+        assert!("esm".parse::<FileFormat>().is_err());
+    }
+
+    #[test]
+    fn keeps_unknown_fields() {
+        let json = r#"{
+            "$schema": "https://json.schemastore.org/tsconfig",
+            "ts-node": {"transpileOnly": true},
+            "compilerOptions": {"strict": true, "someNewUnsupportedProperty": false}
+        }"#;
+        let config = TsConfig::parse_str(json).unwrap();
+
+        assert_eq!(
+            config.extra.keys().collect::<Vec<_>>(),
+            ["$schema", "ts-node"]
+        );
+        assert_eq!(
+            config.extra["ts-node"],
+            serde_json::json!({"transpileOnly": true})
+        );
+        let options = config.compiler_options.as_ref().unwrap();
+        assert_eq!(options.strict, Some(true));
+        assert_eq!(
+            options.extra.get("someNewUnsupportedProperty"),
+            Some(&Value::Bool(false))
+        );
+
+        let written = serde_json::to_value(&config).unwrap();
+        assert_eq!(written["ts-node"]["transpileOnly"], true);
+        assert_eq!(
+            written["compilerOptions"]["someNewUnsupportedProperty"],
+            false
+        );
     }
 
     #[test]
     fn ignores_dangling_commas() {
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true,}}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
 
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true, }}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
 
         let json = r#"{"compilerOptions": {"noImplicitAny": false,"explainFiles": true,
     }}"#;
         let cfg = TsConfig::parse_str(json).unwrap();
-        assert_eq!(cfg.compiler_options.unwrap().explain_files.unwrap(), true);
+        assert!(cfg.compiler_options.unwrap().explain_files.unwrap());
+    }
+
+    #[test]
+    fn ignores_dangling_commas_in_arrays() {
+        let json = r#"{"compilerOptions": {"types": ["node", "jest",], "jsxFactory": "h,}"}}"#;
+        let cfg = TsConfig::parse_str(json).unwrap();
+        let compiler_options = cfg.compiler_options.unwrap();
+        assert_eq!(
+            compiler_options.types,
+            Some(vec!["node".to_string(), "jest".to_string()])
+        );
+        assert_eq!(compiler_options.jsx_factory, Some("h,}".to_string()));
     }
 
     #[test]
@@ -931,7 +3018,7 @@ mod test {
         let mut value1: Value = parse_to_value(json_1).unwrap();
         let value2: Value = parse_to_value(json_2).unwrap();
 
-        merge(&mut value1, value2);
+        merge_config(&mut value1, value2);
 
         let value: TsConfig = serde_json::from_value(value1).unwrap();
 
@@ -1014,6 +3101,373 @@ mod test {
         assert_eq!(config.compiler_options.unwrap().jsx, Some(Jsx::ReactNative));
     }
 
+    #[test]
+    fn parse_extends_array() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/tsconfig.extends_array.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+        let compiler_options = config.compiler_options.unwrap();
+
+        assert_eq!(compiler_options.use_define_for_class_fields, Some(false));
+        assert_eq!(compiler_options.strict, Some(true));
+        assert_eq!(compiler_options.declaration, Some(true));
+        // The second base overrides the first.
+        assert_eq!(compiler_options.trace_resolution, Some(false));
+        assert_eq!(compiler_options.jsx, Some(Jsx::React));
+    }
+
+    #[test]
+    fn parse_extends_string_or_array() {
+        let config = TsConfig::parse_str(r#"{"extends": "./base.json"}"#).unwrap();
+        assert_eq!(
+            config.extends,
+            Some(Extends::Single("./base.json".to_string()))
+        );
+
+        let config = TsConfig::parse_str(r#"{"extends": ["./a.json", "./b.json"]}"#).unwrap();
+        assert_eq!(
+            config.extends.unwrap().as_slice(),
+            &["./a.json".to_string(), "./b.json".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_extends_from_node_modules() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/d/tsconfig.packages.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+        let compiler_options = config.compiler_options.unwrap();
+
+        assert_eq!(compiler_options.target, Some(Target::Es2022));
+        assert_eq!(compiler_options.strict, Some(true));
+        assert_eq!(compiler_options.no_emit, Some(true));
+        assert_eq!(compiler_options.no_implicit_returns, Some(true));
+        assert_eq!(compiler_options.skip_lib_check, Some(true));
+        assert_eq!(compiler_options.declaration, Some(true));
+    }
+
+    #[test]
+    fn detects_circular_extends() {
+        let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/cycle");
+        let a = dir.join("tsconfig.a.json").canonicalize().unwrap();
+        let b = dir.join("tsconfig.b.json").canonicalize().unwrap();
+
+        match TsConfig::parse_file(&a) {
+            Err(ConfigError::CircularExtends(cycle)) => {
+                assert_eq!(cycle, vec![a.clone(), b.clone(), a.clone()])
+            }
+            other => panic!("expected a circularity error, got {:?}", other),
+        }
+
+        match parse_file_to_value(&b) {
+            Err(ConfigError::CircularExtends(cycle)) => assert_eq!(cycle, vec![b.clone(), a, b]),
+            other => panic!("expected a circularity error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rebases_inherited_paths() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/rebase/tsconfig.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+        let compiler_options = config.compiler_options.unwrap();
+
+        // Declared by the top-level file itself, so left as written.
+        assert_eq!(compiler_options.out_dir, Some("./out".to_string()));
+        assert_eq!(compiler_options.root_dir, Some("base/src".to_string()));
+        assert_eq!(compiler_options.base_url, Some("base".to_string()));
+        assert_eq!(
+            compiler_options.root_dirs,
+            Some(vec!["base/src".to_string(), "base/generated".to_string()])
+        );
+        assert_eq!(
+            compiler_options.type_roots,
+            Some(vec![
+                "base/types".to_string(),
+                "../node_modules/@types".to_string()
+            ])
+        );
+        assert_eq!(
+            compiler_options.declaration_dir,
+            Some("base/dist/types".to_string())
+        );
+        assert_eq!(
+            compiler_options.ts_build_info_file,
+            Some("base/.tsbuildinfo".to_string())
+        );
+        // Relative to `baseUrl`, which has itself been rebased.
+        assert_eq!(
+            compiler_options.paths.unwrap().get("@app/*"),
+            Some(&vec!["./src/app/*".to_string()])
+        );
+
+        assert_eq!(config.include, Some(vec!["base/src/**/*".to_string()]));
+        assert_eq!(
+            config.exclude,
+            Some(vec!["base/src/**/*.test.ts".to_string()])
+        );
+        assert_eq!(
+            config.files,
+            Some(vec!["base/src/globals.d.ts".to_string()])
+        );
+    }
+
+    #[test]
+    fn rebases_inherited_paths_without_base_url() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/rebase/tsconfig.paths_only.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+
+        assert_eq!(
+            config
+                .compiler_options
+                .unwrap()
+                .paths
+                .unwrap()
+                .get("@lib/*"),
+            Some(&vec![
+                "paths_only/lib/*".to_string(),
+                "/opt/lib/*".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn substitutes_config_dir_template() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/config_dir/tsconfig.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+        let compiler_options = config.compiler_options.unwrap();
+
+        assert_eq!(compiler_options.out_dir, Some("dist".to_string()));
+        assert_eq!(
+            compiler_options.type_roots,
+            Some(vec!["types".to_string(), "shared/types".to_string()])
+        );
+        assert_eq!(
+            compiler_options.paths.unwrap().get("~/*"),
+            Some(&vec!["src/*".to_string()])
+        );
+        assert_eq!(config.include, Some(vec!["src".to_string()]));
+    }
+
+    #[test]
+    fn substitutes_config_dir_in_paths_relative_to_base_url() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/config_dir/tsconfig.base_url.json");
+        let compiler_options = TsConfig::parse_file(&path)
+            .unwrap()
+            .compiler_options
+            .unwrap();
+
+        assert_eq!(compiler_options.base_url, Some("./app".to_string()));
+        assert_eq!(
+            compiler_options.paths.unwrap().get("~/*"),
+            Some(&vec!["../src/*".to_string()])
+        );
+        assert_eq!(compiler_options.out_dir, Some("dist".to_string()));
+
+        // A `baseUrl` that is not a path does not hide the one from the base.
+        let path = path.with_file_name("tsconfig.null_base_url.json");
+        let compiler_options = TsConfig::parse_file(&path)
+            .unwrap()
+            .compiler_options
+            .unwrap();
+        assert_eq!(
+            compiler_options.paths.unwrap().get("~/*"),
+            Some(&vec!["../src/*".to_string()])
+        );
+    }
+
+    #[test]
+    fn inherits_type_acquisition_settings_individually() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/type_acquisition/jsconfig.json");
+        let config = TsConfig::parse_file(&path).unwrap();
+
+        assert_eq!(
+            config.type_acquisition,
+            Some(TypeAcquisition::Object {
+                enable: None,
+                include: Some(vec!["jest".to_string()]),
+                exclude: Some(vec!["jquery".to_string()]),
+                disable_filename_based_type_acquisition: Some(true),
+            })
+        );
+    }
+
+    #[test]
+    fn inherits_and_rebases_watch_options() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/watch/tsconfig.json");
+        let watch_options = TsConfig::parse_file(&path).unwrap().watch_options.unwrap();
+
+        assert_eq!(
+            watch_options.watch_file,
+            Some(WatchFileKind::FixedPollingInterval)
+        );
+        assert_eq!(
+            watch_options.fallback_polling,
+            Some(PollingWatchKind::DynamicPriority)
+        );
+        assert_eq!(watch_options.synchronous_watch_directory, Some(true));
+        assert_eq!(
+            watch_options.exclude_directories,
+            Some(vec!["shared/node_modules".to_string(), "dist".to_string()])
+        );
+        assert_eq!(
+            watch_options.exclude_files,
+            Some(vec!["shared/generated/schema.ts".to_string()])
+        );
+    }
+
+    fn parse_merge_fixture() -> TsConfig {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/merge/tsconfig.json");
+        TsConfig::parse_file(&path).unwrap()
+    }
+
+    #[test]
+    fn inherits_compiler_options_individually() {
+        let compiler_options = parse_merge_fixture().compiler_options.unwrap();
+        assert_eq!(compiler_options.strict, Some(true));
+        assert_eq!(compiler_options.types, Some(vec!["node".to_string()]));
+    }
+
+    #[test]
+    fn unsets_inherited_options_set_to_null() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/unset/tsconfig.json");
+        let compiler_options = TsConfig::parse_file(&path)
+            .unwrap()
+            .compiler_options
+            .unwrap();
+        assert_eq!(compiler_options.out_dir, None);
+        assert_eq!(compiler_options.strict, Some(true));
+
+        let flattened = flatten_file(&path).unwrap();
+        assert_eq!(
+            flattened["compilerOptions"],
+            serde_json::json!({"strict": true})
+        );
+    }
+
+    #[test]
+    fn replaces_array_compiler_options() {
+        let compiler_options = parse_merge_fixture().compiler_options.unwrap();
+        assert_eq!(compiler_options.lib, Some(vec![Lib::Es2022]));
+        assert_eq!(compiler_options.root_dirs, Some(vec!["./src".to_string()]));
+    }
+
+    #[test]
+    fn replaces_paths() {
+        let paths = parse_merge_fixture()
+            .compiler_options
+            .unwrap()
+            .paths
+            .unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths.get("@app/*"), Some(&vec!["./app/*".to_string()]));
+    }
+
+    #[test]
+    fn replaces_files_include_exclude_individually() {
+        let config = parse_merge_fixture();
+        assert_eq!(config.include, Some(vec!["./app/**/*".to_string()]));
+        assert_eq!(config.files, Some(vec!["./globals.d.ts".to_string()]));
+        assert_eq!(config.exclude, Some(vec!["./dist".to_string()]));
+    }
+
+    #[test]
+    fn does_not_inherit_references() {
+        let config = parse_merge_fixture();
+        assert!(config.references.is_none());
+        assert_eq!(
+            config.extends,
+            Some(Extends::Single("./tsconfig.base.json".to_string()))
+        );
+    }
+
+    #[test]
+    fn locates_syntax_errors_in_bases() {
+        let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/errors");
+        let path = dir.join("tsconfig.syntax.json");
+
+        let err = TsConfig::parse_file(&path).unwrap_err();
+        let location = err.location().unwrap().clone();
+        assert!(matches!(
+            err,
+            ConfigError::SyntaxError {
+                error: SyntaxError {
+                    kind: SyntaxErrorKind::Expected(","),
+                    ..
+                },
+                ..
+            }
+        ));
+        assert_eq!(location.path, Some(dir.join("./tsconfig.syntax_base.json")));
+        assert_eq!((location.line, location.column), (4, 9));
+        assert_eq!(location.span.len(), r#""noEmit""#.len());
+        assert_eq!(location.extends_chain, vec![path.canonicalize().unwrap()]);
+    }
+
+    #[test]
+    fn locates_invalid_values() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/errors/tsconfig.invalid_value.json");
+
+        let err = TsConfig::parse_file(&path).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidValue { .. }));
+        let location = err.location().unwrap();
+        assert_eq!((location.line, location.column), (4, 19));
+        assert!(location.extends_chain.is_empty());
+
+        // Only type checking fails, so the value can still be read.
+        parse_file_to_value(&path).unwrap();
+
+        let err = TsConfig::parse_str("{\n  \"files\": 5\n}").unwrap_err();
+        assert_eq!(
+            err.to_string().split(':').take(2).collect::<Vec<_>>(),
+            ["2", "12"]
+        );
+    }
+
+    #[test]
+    fn locates_missing_bases() {
+        let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test/errors");
+
+        let err = TsConfig::parse_file(&dir.join("tsconfig.missing.json")).unwrap_err();
+        match &err {
+            ConfigError::ExtendsNotFound {
+                specifier,
+                location,
+            } => {
+                assert_eq!(specifier, "@missing/tsconfig");
+                assert_eq!((location.line, location.column), (2, 50));
+            }
+            other => panic!("expected a missing base, got {:?}", other),
+        }
+
+        let path = dir.join("tsconfig.missing_file.json");
+        match TsConfig::parse_file(&path).unwrap_err() {
+            ConfigError::CouldNotFindFile {
+                path: missing,
+                extends_chain,
+                ..
+            } => {
+                assert_eq!(missing, dir.join("./does_not_exist.json"));
+                assert_eq!(extends_chain, vec![path.canonicalize().unwrap()]);
+            }
+            other => panic!("expected a missing file, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn locates_syntax_errors_in_strings() {
+        let err = parse_to_value("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!(err.to_string(), "3:7: ':' expected");
+    }
+
     #[test]
     fn parse_no_extension_file() {
         let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
@@ -1032,4 +3486,51 @@ mod test {
             Some(false)
         );
     }
+
+    #[test]
+    fn serializes_canonical_spellings() {
+        let json = r#"{
+            "compilerOptions": {
+                "target": "es2020",
+                "module": "commonjs",
+                "lib": ["DOM", "dom.Iterable", "es2021.weakref"],
+                "jsx": "react-jsx",
+                "emitBOM": true,
+                "moduleResolution": "node16",
+                "paths": { "b/*": ["src/b/*"], "a/*": ["src/a/*"] }
+            },
+            "references": [{ "path": "../shared" }]
+        }"#;
+        let config = TsConfig::parse_str(json).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "compilerOptions": {
+                    "jsx": "react-jsx",
+                    "lib": ["dom", "dom.iterable", "es2021.weakref"],
+                    "module": "CommonJS",
+                    "target": "ES2020",
+                    "moduleResolution": "node16",
+                    "paths": { "a/*": ["src/a/*"], "b/*": ["src/b/*"] },
+                    "emitBOM": true
+                },
+                "references": [{ "path": "../shared" }]
+            })
+        );
+        assert!(serde_json::to_string(&config)
+            .unwrap()
+            .contains(r#""paths":{"a/*":["src/a/*"],"b/*":["src/b/*"]}"#));
+    }
+
+    #[test]
+    fn round_trips_through_serialization() {
+        let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("test/tsconfig.complete.json");
+        let config = TsConfig::parse_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+        let written = serde_json::to_string_pretty(&config).unwrap();
+        let reparsed = TsConfig::parse_str(&written).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&reparsed).unwrap(), written);
+    }
 }
@@ -0,0 +1,244 @@
+//! Tracking which file of an `extends` chain supplied each effective option.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::{
+    load_rebased_layers, merge_layers, rebase, ConfigError, Layer, Result, TsConfig,
+    MERGED_SECTIONS, NON_INHERITED_FIELDS,
+};
+
+/// The merged contents of a .tsconfig file, along with the file each effective field came from.
+#[derive(Debug, Clone)]
+pub struct Provenance {
+    /// The merged configuration, as returned by [crate::parse_file_to_value].
+    pub value: Value,
+    /// The source of every effective field, keyed by its dotted path, e.g. `"include"` or
+    /// `"compilerOptions.strict"`.
+    pub fields: BTreeMap<String, FieldSource>,
+}
+
+/// Where the effective value of a field came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSource {
+    /// The file that supplied the effective value.
+    pub path: PathBuf,
+    /// The effective value.
+    pub value: Value,
+    /// The values other files of the chain set and which were overridden, nearest first.
+    pub overridden: Vec<(PathBuf, Value)>,
+}
+
+impl Provenance {
+    /// Returns the source of a field given its dotted path, e.g. `"compilerOptions.strict"`.
+    pub fn get(&self, field: &str) -> Option<&FieldSource> {
+        self.fields.get(field)
+    }
+
+    /// Deserializes the merged configuration into a [TsConfig].
+    pub fn config(&self) -> Result<TsConfig> {
+        Ok(serde_json::from_value(self.value.clone())?)
+    }
+}
+
+/// Lists every effective field with the file that set it and the values it overrode.
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (field, source) in &self.fields {
+            write!(
+                f,
+                "{}: {} (from {}",
+                field,
+                source.value,
+                source.path.display()
+            )?;
+            for (path, value) in &source.overridden {
+                write!(f, ", overriding {} from {}", value, path.display())?;
+            }
+            writeln!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a .tsconfig file like [crate::parse_file_to_value], also recording which file of the
+/// `extends` chain supplied each effective field.
+///
+/// Values are reported after path rebasing, so paths are relative to the directory of `path`.
+/// File paths are made absolute.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::parse_file_with_provenance;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/tsconfig.inherits.json");
+/// let provenance = parse_file_with_provenance(&path).unwrap();
+///
+/// let source = provenance.get("compilerOptions.useDefineForClassFields").unwrap();
+/// assert!(source.path.ends_with("test/b/tsconfig.base.json"));
+/// ```
+pub fn parse_file_with_provenance<P: AsRef<Path>>(path: &P) -> Result<Provenance> {
+    let layers = load_rebased_layers(path.as_ref())?;
+    let top = layers.len() - 1;
+
+    let mut fields: BTreeMap<String, FieldSource> = BTreeMap::new();
+    for (index, layer) in layers.iter().enumerate() {
+        let layer_path = rebase::absolute(&layer.path)
+            .map_err(|e| ConfigError::io(&layer.path, &layer.extends_chain, e))?;
+        for (field, value) in layer_fields(layer, index == top) {
+            match fields.get_mut(&field) {
+                Some(source) => {
+                    let previous_path = std::mem::replace(&mut source.path, layer_path.clone());
+                    let previous_value = std::mem::replace(&mut source.value, value.clone());
+                    source.overridden.insert(0, (previous_path, previous_value));
+                }
+                None => {
+                    fields.insert(
+                        field,
+                        FieldSource {
+                            path: layer_path.clone(),
+                            value: value.clone(),
+                            overridden: Vec::new(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    // Drop the fields a later file removed, by unsetting them or replacing their whole section.
+    let value = merge_layers(layers);
+    fields.retain(|field, source| merged_field(&value, field) == Some(&source.value));
+
+    Ok(Provenance { value, fields })
+}
+
+/// The value of a field of [layer_fields] in the merged configuration.
+fn merged_field<'a>(merged: &'a Value, field: &str) -> Option<&'a Value> {
+    match field.split_once('.') {
+        Some((section, option)) if MERGED_SECTIONS.contains(&section) => {
+            merged.get(section)?.get(option)
+        }
+        _ => merged.get(field),
+    }
+}
+
+/// The fields a layer contributes, with the options of merged sections listed individually.
+fn layer_fields(layer: &Layer, is_top: bool) -> Vec<(String, &Value)> {
+    let mut fields = Vec::new();
+    let object = match &layer.value {
+        Value::Object(object) => object,
+        _ => return fields,
+    };
+
+    for (key, value) in object {
+        if value.is_null() || (!is_top && NON_INHERITED_FIELDS.contains(&key.as_str())) {
+            continue;
+        }
+        match value {
+            Value::Object(section) if MERGED_SECTIONS.contains(&key.as_str()) => {
+                for (option, value) in section {
+                    if !value.is_null() {
+                        fields.push((format!("{}.{}", key, option), value));
+                    }
+                }
+            }
+            value => fields.push((key.clone(), value)),
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_dir() -> PathBuf {
+        rebase::absolute(&Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test"))
+            .unwrap()
+    }
+
+    #[test]
+    fn tracks_sources_through_chain() {
+        let dir = test_dir();
+        let provenance =
+            parse_file_with_provenance(&dir.join("a/tsconfig.inherits_again.json")).unwrap();
+
+        let jsx = provenance.get("compilerOptions.jsx").unwrap();
+        assert_eq!(jsx.path, dir.join("a/tsconfig.inherits_again.json"));
+        assert_eq!(jsx.value, "react-native");
+        assert_eq!(
+            jsx.overridden,
+            vec![
+                (
+                    dir.join("tsconfig.inherits.json"),
+                    Value::from("react-jsxdev")
+                ),
+                (dir.join("b/tsconfig.base.json"), Value::from("preserve")),
+            ]
+        );
+
+        let use_define = provenance
+            .get("compilerOptions.useDefineForClassFields")
+            .unwrap();
+        assert_eq!(use_define.path, dir.join("b/tsconfig.base.json"));
+        assert!(use_define.overridden.is_empty());
+
+        assert_eq!(
+            provenance.config().unwrap().compiler_options.unwrap().jsx,
+            Some(crate::Jsx::ReactNative)
+        );
+    }
+
+    #[test]
+    fn skips_fields_that_are_not_inherited() {
+        let dir = test_dir();
+        let provenance = parse_file_with_provenance(&dir.join("merge/tsconfig.json")).unwrap();
+
+        assert!(provenance.get("references").is_none());
+        assert_eq!(
+            provenance.get("extends").unwrap().path,
+            dir.join("merge/tsconfig.json")
+        );
+        assert_eq!(
+            provenance.get("exclude").unwrap().path,
+            dir.join("merge/tsconfig.base.json")
+        );
+    }
+
+    #[test]
+    fn drops_fields_removed_by_later_files() {
+        let dir = test_dir();
+        let provenance = parse_file_with_provenance(&dir.join("provenance/tsconfig.json")).unwrap();
+
+        assert_eq!(
+            provenance.get("compilerOptions.strict").unwrap().path,
+            dir.join("provenance/tsconfig.base.json")
+        );
+        assert!(provenance.get("compilerOptions.outDir").is_none());
+        assert!(provenance.get("typeAcquisition.enable").is_none());
+        assert!(provenance.get("typeAcquisition.include").is_none());
+
+        let type_acquisition = provenance.get("typeAcquisition").unwrap();
+        assert_eq!(type_acquisition.path, dir.join("provenance/tsconfig.json"));
+        assert_eq!(type_acquisition.value, false);
+    }
+
+    #[test]
+    fn displays_sources() {
+        let dir = test_dir();
+        let provenance = parse_file_with_provenance(&dir.join("tsconfig.inherits.json")).unwrap();
+
+        let explanation = provenance.to_string();
+        assert!(explanation.contains(&format!(
+            "compilerOptions.jsx: \"react-jsxdev\" (from {}, overriding \"preserve\" from {})",
+            dir.join("tsconfig.inherits.json").display(),
+            dir.join("b/tsconfig.base.json").display()
+        )));
+    }
+}
@@ -0,0 +1,262 @@
+//! Rebasing of path-valued options inherited through `extends`.
+//!
+//! tsc resolves paths in a configuration file relative to the file that declared them, not the
+//! file that extends it. When a base configuration lives in another directory, its paths are
+//! rewritten here so that the merged configuration is expressed relative to the top-level file.
+
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::Value;
+
+/// Template that paths may start with to refer to the directory of the top-level configuration.
+const CONFIG_DIR_TEMPLATE: &str = "${configDir}";
+
+/// Top-level fields holding lists of paths.
+const PATH_LIST_FIELDS: &[&str] = &["files", "include", "exclude"];
+
+/// Compiler options holding a single path.
+const PATH_OPTIONS: &[&str] = &[
+    "baseUrl",
+    "declarationDir",
+    "generateTrace",
+    "out",
+    "outDir",
+    "outFile",
+    "rootDir",
+    "tsBuildInfoFile",
+];
+
+/// Compiler options holding lists of paths.
+const PATH_LIST_OPTIONS: &[&str] = &["rootDirs", "typeRoots"];
+
+/// Watch options holding lists of paths.
+const PATH_LIST_WATCH_OPTIONS: &[&str] = &["excludeDirectories", "excludeFiles"];
+
+/// Rewrites every path-valued field of `value` (except `paths`, see [rebase_paths_option]) from
+/// being relative to the declaring file to being relative to another directory, `rel` being the
+/// path from that directory to the declaring file's directory.
+pub(crate) fn rebase_config(value: &mut Value, rel: &Path) {
+    for_each_path(value, &mut |s| *s = rebase_path(s, rel));
+}
+
+/// Rewrites the substitutions of `compilerOptions.paths`.
+///
+/// These are relative to `baseUrl` when it is set, so this should only be applied when the
+/// merged configuration has no `baseUrl`.
+pub(crate) fn rebase_paths_option(value: &mut Value, rel: &Path) {
+    for_each_paths_substitution(value, &mut |s| *s = rebase_path(s, rel));
+}
+
+/// Replaces the `${configDir}` template in every path-valued field of `value` but `paths`, see
+/// [substitute_config_dir_in_paths].
+///
+/// The template stands for the directory of the top-level configuration, which is the directory
+/// every other path of a merged configuration is relative to, so the result is relative to it too.
+pub(crate) fn substitute_config_dir(value: &mut Value) {
+    for_each_path(value, &mut |s| substitute(s, Path::new("")));
+}
+
+/// Replaces the `${configDir}` template in the substitutions of `compilerOptions.paths`, `rel`
+/// being the path from the directory they are relative to (that of `baseUrl`, if set) to the
+/// directory of the top-level configuration.
+pub(crate) fn substitute_config_dir_in_paths(value: &mut Value, rel: &Path) {
+    for_each_paths_substitution(value, &mut |s| substitute(s, rel));
+}
+
+fn substitute(path: &mut String, rel: &Path) {
+    if let Some(rest) = path.strip_prefix(CONFIG_DIR_TEMPLATE) {
+        *path = to_slash(&normalize(&rel.join(format!("./{}", rest))));
+    }
+}
+
+fn for_each_path(value: &mut Value, f: &mut dyn FnMut(&mut String)) {
+    for field in PATH_LIST_FIELDS {
+        for_each_in_list(value.get_mut(*field), f);
+    }
+
+    if let Some(options) = value.get_mut("compilerOptions") {
+        for option in PATH_OPTIONS {
+            if let Some(Value::String(s)) = options.get_mut(*option) {
+                f(s);
+            }
+        }
+        for option in PATH_LIST_OPTIONS {
+            for_each_in_list(options.get_mut(*option), f);
+        }
+    }
+
+    if let Some(options) = value.get_mut("watchOptions") {
+        for option in PATH_LIST_WATCH_OPTIONS {
+            for_each_in_list(options.get_mut(*option), f);
+        }
+    }
+}
+
+fn for_each_paths_substitution(value: &mut Value, f: &mut dyn FnMut(&mut String)) {
+    if let Some(Value::Object(paths)) = value
+        .get_mut("compilerOptions")
+        .and_then(|options| options.get_mut("paths"))
+    {
+        for substitutions in paths.values_mut() {
+            for_each_in_list(Some(substitutions), f);
+        }
+    }
+}
+
+fn for_each_in_list(value: Option<&mut Value>, f: &mut dyn FnMut(&mut String)) {
+    if let Some(Value::Array(items)) = value {
+        for item in items {
+            if let Value::String(s) = item {
+                f(s);
+            }
+        }
+    }
+}
+
+/// Rebases a single path. Absolute paths and paths using the `${configDir}` template are left alone.
+pub(crate) fn rebase_path(path: &str, rel: &Path) -> String {
+    if path.starts_with(CONFIG_DIR_TEMPLATE) || Path::new(path).is_absolute() {
+        return path.to_string();
+    }
+    to_slash(&normalize(&rel.join(path)))
+}
+
+/// Makes `path` absolute against the current directory, and lexically normalizes it.
+pub(crate) fn absolute(path: &Path) -> std::io::Result<PathBuf> {
+    if path.is_absolute() {
+        Ok(normalize(path))
+    } else {
+        Ok(normalize(&std::env::current_dir()?.join(path)))
+    }
+}
+
+/// Removes `.` components and resolves `..` components without touching the file system.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => result.push(".."),
+            },
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// The path from the absolute, normalized directory `from` to the absolute, normalized `to`.
+///
+/// If the two do not share a root, `to` is returned unchanged.
+pub(crate) fn relative(from: &Path, to: &Path) -> PathBuf {
+    let mut from_components = from.components().peekable();
+    let mut to_components = to.components().peekable();
+
+    match (from_components.peek(), to_components.peek()) {
+        (Some(a), Some(b)) if a == b => {}
+        _ => return to.to_path_buf(),
+    }
+
+    while let (Some(a), Some(b)) = (from_components.peek(), to_components.peek()) {
+        if a != b {
+            break;
+        }
+        from_components.next();
+        to_components.next();
+    }
+
+    let mut result = PathBuf::new();
+    for _ in from_components {
+        result.push("..");
+    }
+    for component in to_components {
+        result.push(component);
+    }
+    result
+}
+
+/// Renders a path with forward slashes, as tsc does.
+pub(crate) fn to_slash(path: &Path) -> String {
+    if path.as_os_str().is_empty() {
+        return ".".to_string();
+    }
+
+    path.components()
+        .map(|component| match component {
+            Component::RootDir => String::new(),
+            other => other.as_os_str().to_string_lossy().into_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rebases_relative_paths() {
+        assert_eq!(rebase_path("./dist", Path::new("base")), "base/dist");
+        assert_eq!(rebase_path("src/**/*", Path::new("base")), "base/src/**/*");
+        assert_eq!(rebase_path(".", Path::new("base")), "base");
+        assert_eq!(rebase_path("../types", Path::new("a/b")), "a/types");
+        assert_eq!(rebase_path("./src", Path::new("..")), "../src");
+        assert_eq!(rebase_path("../../x", Path::new("a")), "../x");
+    }
+
+    #[test]
+    fn leaves_absolute_and_templated_paths() {
+        assert_eq!(rebase_path("/opt/dist", Path::new("base")), "/opt/dist");
+        assert_eq!(
+            rebase_path("${configDir}/dist", Path::new("base")),
+            "${configDir}/dist"
+        );
+    }
+
+    #[test]
+    fn substitutes_config_dir() {
+        let mut value = serde_json::json!({
+            "include": ["${configDir}/src", "lib"],
+            "compilerOptions": {
+                "outDir": "${configDir}/dist",
+                "rootDir": "${configDir}",
+                "paths": { "@/*": ["${configDir}/src/*"] },
+                "jsxFactory": "${configDir}"
+            }
+        });
+        substitute_config_dir(&mut value);
+
+        assert_eq!(value["include"], serde_json::json!(["src", "lib"]));
+        assert_eq!(value["compilerOptions"]["outDir"], "dist");
+        assert_eq!(value["compilerOptions"]["rootDir"], ".");
+        assert_eq!(
+            value["compilerOptions"]["paths"]["@/*"][0],
+            "${configDir}/src/*"
+        );
+
+        substitute_config_dir_in_paths(&mut value, Path::new("../.."));
+        assert_eq!(value["compilerOptions"]["paths"]["@/*"][0], "../../src/*");
+        // Not a path option.
+        assert_eq!(value["compilerOptions"]["jsxFactory"], "${configDir}");
+    }
+
+    #[test]
+    fn computes_relative_directories() {
+        assert_eq!(
+            relative(Path::new("/a/b"), Path::new("/a/b/c/d")),
+            Path::new("c/d")
+        );
+        assert_eq!(
+            relative(Path::new("/a/b/c"), Path::new("/a/d")),
+            Path::new("../../d")
+        );
+        assert_eq!(
+            relative(Path::new("/a/b"), Path::new("/a/b")),
+            Path::new("")
+        );
+    }
+}
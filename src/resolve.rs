@@ -0,0 +1,304 @@
+//! Resolution of `extends` specifiers to configuration files.
+//!
+//! Relative and absolute specifiers are resolved against the directory of the extending file.
+//! Anything else is looked up as a package in `node_modules`, following the same order as tsc:
+//! the package's `exports` map, then its `tsconfig` field, then a `tsconfig.json` in the package root.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::{rebase, ConfigError, Result};
+
+/// Conditions tsc matches against `exports` when resolving a configuration file.
+const CONDITIONS: &[&str] = &["require", "types", "node", "default"];
+
+/// Resolves an `extends` specifier found in the file at `path` to the path of the base configuration.
+///
+/// Returns `None` if a package specifier cannot be found. Relative specifiers always resolve; it
+/// is up to the caller to report the file as missing.
+pub(crate) fn resolve_extends_path(path: &Path, specifier: &str) -> Result<Option<PathBuf>> {
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    if is_relative_specifier(specifier) {
+        let extends_path = dir.join(specifier);
+        // This may or may not have a `.json` extension
+        if extends_path.is_file() || specifier.ends_with(".json") {
+            return Ok(Some(extends_path));
+        }
+
+        let extends_path_str = extends_path
+            .to_str()
+            .ok_or_else(|| ConfigError::InvalidPath(extends_path.to_string_lossy().to_string()))?;
+        return Ok(Some(PathBuf::from(extends_path_str.to_string() + ".json")));
+    }
+
+    Ok(rebase::absolute(dir)
+        .ok()
+        .and_then(|dir| resolve_package(specifier, &dir)))
+}
+
+fn is_relative_specifier(specifier: &str) -> bool {
+    let specifier = specifier.replace('\\', "/");
+    specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(&specifier).is_absolute()
+}
+
+/// Walks up from `dir` looking for `specifier` in each `node_modules` directory.
+fn resolve_package(specifier: &str, dir: &Path) -> Option<PathBuf> {
+    let (package_name, rest) = parse_package_name(specifier);
+
+    dir.ancestors()
+        .filter(|ancestor| {
+            ancestor
+                .file_name()
+                .map_or(true, |name| name != "node_modules")
+        })
+        .map(|ancestor| ancestor.join("node_modules"))
+        .filter(|node_modules| node_modules.is_dir())
+        .find_map(|node_modules| {
+            load_from_package(
+                &node_modules.join(package_name),
+                &node_modules.join(specifier),
+                rest,
+            )
+        })
+}
+
+/// Splits a specifier into the package name (including any scope) and the subpath within it.
+fn parse_package_name(specifier: &str) -> (&str, &str) {
+    let mut separators = specifier.match_indices('/').map(|(i, _)| i);
+    let end = if specifier.starts_with('@') {
+        separators.nth(1)
+    } else {
+        separators.next()
+    };
+
+    match end {
+        Some(end) => (&specifier[..end], &specifier[end + 1..]),
+        None => (specifier, ""),
+    }
+}
+
+fn load_from_package(package_dir: &Path, candidate: &Path, rest: &str) -> Option<PathBuf> {
+    let package_json = read_package_json(package_dir);
+
+    if let Some(exports) = package_json.as_ref().and_then(|p| p.get("exports")) {
+        // A package with an `exports` map only exposes what the map lists.
+        let subpath = if rest.is_empty() {
+            ".".to_string()
+        } else {
+            format!("./{}", rest)
+        };
+        return resolve_exports(package_dir, exports, &subpath);
+    }
+
+    if !rest.is_empty() {
+        if let Some(file) = load_file(candidate) {
+            return Some(file);
+        }
+        return load_directory(candidate, read_package_json(candidate).as_ref());
+    }
+
+    load_directory(package_dir, package_json.as_ref())
+}
+
+fn read_package_json(dir: &Path) -> Option<Value> {
+    let contents = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Loads `candidate` as a file, appending `.json` if necessary.
+fn load_file(candidate: &Path) -> Option<PathBuf> {
+    if candidate.extension().is_some_and(|ext| ext == "json") && candidate.is_file() {
+        return Some(candidate.to_path_buf());
+    }
+
+    let mut with_ext = candidate.as_os_str().to_owned();
+    with_ext.push(".json");
+    let with_ext = PathBuf::from(with_ext);
+    if with_ext.is_file() {
+        Some(with_ext)
+    } else {
+        None
+    }
+}
+
+/// Loads a directory through its `package.json` `tsconfig` field, falling back to `tsconfig.json`.
+fn load_directory(dir: &Path, package_json: Option<&Value>) -> Option<PathBuf> {
+    if let Some(tsconfig) = package_json
+        .and_then(|p| p.get("tsconfig"))
+        .and_then(Value::as_str)
+    {
+        let target = dir.join(tsconfig);
+        if let Some(file) = load_file(&target) {
+            return Some(file);
+        }
+        if let Some(file) = load_file(&target.join("tsconfig")) {
+            return Some(file);
+        }
+    }
+
+    load_file(&dir.join("tsconfig"))
+}
+
+fn resolve_exports(package_dir: &Path, exports: &Value, subpath: &str) -> Option<PathBuf> {
+    let is_subpath_map = match exports {
+        Value::Object(map) => map.keys().any(|k| k.starts_with('.')),
+        _ => false,
+    };
+
+    if !is_subpath_map {
+        // Sugar for `{ ".": exports }`.
+        return if subpath == "." {
+            resolve_export_target(package_dir, exports, None)
+        } else {
+            None
+        };
+    }
+
+    let map = exports.as_object()?;
+    if let Some(target) = map.get(subpath) {
+        if !subpath.contains('*') {
+            return resolve_export_target(package_dir, target, None);
+        }
+    }
+
+    // Among the patterns that match, the one with the longest prefix wins.
+    map.iter()
+        .filter_map(|(key, target)| {
+            let star = key.find('*')?;
+            let (prefix, suffix) = (&key[..star], &key[star + 1..]);
+            if subpath.len() >= key.len() - 1
+                && subpath.starts_with(prefix)
+                && subpath.ends_with(suffix)
+            {
+                let matched = &subpath[prefix.len()..subpath.len() - suffix.len()];
+                Some((prefix.len(), matched, target))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(prefix_len, _, _)| *prefix_len)
+        .and_then(|(_, matched, target)| resolve_export_target(package_dir, target, Some(matched)))
+}
+
+fn resolve_export_target(
+    package_dir: &Path,
+    target: &Value,
+    pattern_match: Option<&str>,
+) -> Option<PathBuf> {
+    match target {
+        Value::String(target) => {
+            let target = target.strip_prefix("./")?;
+            let target = match pattern_match {
+                Some(matched) => target.replace('*', matched),
+                None => target.to_string(),
+            };
+            let path = package_dir.join(target);
+            if path.is_file() {
+                Some(path)
+            } else {
+                None
+            }
+        }
+        Value::Array(targets) => targets
+            .iter()
+            .find_map(|t| resolve_export_target(package_dir, t, pattern_match)),
+        Value::Object(conditions) => conditions
+            .iter()
+            .filter(|(condition, _)| CONDITIONS.contains(&condition.as_str()))
+            .find_map(|(_, t)| resolve_export_target(package_dir, t, pattern_match)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_dir() -> PathBuf {
+        Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test")
+    }
+
+    fn resolve(specifier: &str) -> PathBuf {
+        let from = test_dir().join("d/tsconfig.packages.json");
+        resolve_extends_path(&from, specifier).unwrap().unwrap()
+    }
+
+    #[test]
+    fn splits_package_names() {
+        assert_eq!(parse_package_name("plain-config"), ("plain-config", ""));
+        assert_eq!(
+            parse_package_name("plain-config/tsconfig.json"),
+            ("plain-config", "tsconfig.json")
+        );
+        assert_eq!(
+            parse_package_name("@tsconfig/node18"),
+            ("@tsconfig/node18", "")
+        );
+        assert_eq!(
+            parse_package_name("@tsconfig/node18/tsconfig.json"),
+            ("@tsconfig/node18", "tsconfig.json")
+        );
+    }
+
+    #[test]
+    fn resolves_file_in_scoped_package() {
+        assert_eq!(
+            resolve("@tsconfig/node18/tsconfig.json"),
+            test_dir().join("node_modules/@tsconfig/node18/tsconfig.json")
+        );
+        assert_eq!(
+            resolve("@tsconfig/node18/tsconfig"),
+            test_dir().join("node_modules/@tsconfig/node18/tsconfig.json")
+        );
+    }
+
+    #[test]
+    fn resolves_package_json_tsconfig_field() {
+        assert_eq!(
+            resolve("@company/tsconfig"),
+            test_dir().join("node_modules/@company/tsconfig/base.json")
+        );
+    }
+
+    #[test]
+    fn resolves_default_tsconfig_json() {
+        assert_eq!(
+            resolve("plain-config"),
+            test_dir().join("node_modules/plain-config/tsconfig.json")
+        );
+    }
+
+    #[test]
+    fn resolves_exports() {
+        let package = test_dir().join("node_modules/exported-config");
+        assert_eq!(
+            resolve("exported-config"),
+            package.join("configs/default.json")
+        );
+        assert_eq!(
+            resolve("exported-config/strict"),
+            package.join("configs/strict.json")
+        );
+        assert_eq!(
+            resolve("exported-config/presets/strict"),
+            package.join("configs/strict.json")
+        );
+
+        let from = test_dir().join("d/tsconfig.packages.json");
+        // Files not listed in `exports` are not reachable.
+        assert_eq!(
+            resolve_extends_path(&from, "exported-config/configs/strict.json").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn missing_package_is_an_error() {
+        let from = test_dir().join("d/tsconfig.packages.json");
+        assert_eq!(resolve_extends_path(&from, "not-installed").unwrap(), None);
+    }
+}
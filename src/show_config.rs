@@ -0,0 +1,195 @@
+//! Flattening of an `extends` chain into a single standalone configuration, as `tsc --showConfig`
+//! prints it.
+
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{load_rebased_layers, merge_layers, Layer, Result, MERGED_SECTIONS};
+
+/// Top-level fields `tsc --showConfig` prints, in the order it prints them.
+const SHOW_CONFIG_FIELDS: &[&str] = &[
+    "compilerOptions",
+    "watchOptions",
+    "references",
+    "files",
+    "include",
+    "exclude",
+    "compileOnSave",
+];
+
+/// Resolves the whole `extends` chain of a .tsconfig file into a single configuration that no
+/// longer extends anything.
+///
+/// Paths are rebased to be relative to the directory of `path`, so the result can be written next
+/// to it and used without any of its bases. As with `tsc --showConfig`, only the fields tsc
+/// understands are kept, options unset with `null` are dropped, and options appear in the order
+/// they were first set along the chain, bases first. Unlike tsc, `files` is not expanded from
+/// `include`.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::flatten_file;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/tsconfig.inherits.json");
+/// let config = flatten_file(&path).unwrap();
+///
+/// assert!(config.get("extends").is_none());
+/// assert_eq!(config["compilerOptions"]["useDefineForClassFields"], false);
+/// ```
+pub fn flatten_file<P: AsRef<Path>>(path: &P) -> Result<Value> {
+    let layers = load_rebased_layers(path.as_ref())?;
+    let orders: Vec<Vec<String>> = MERGED_SECTIONS
+        .iter()
+        .map(|section| option_order(&layers, section))
+        .collect();
+    let mut merged = match merge_layers(layers) {
+        Value::Object(merged) => merged,
+        _ => Map::new(),
+    };
+
+    let mut flattened = Map::new();
+    for field in SHOW_CONFIG_FIELDS {
+        let value = match merged.remove(*field) {
+            Some(Value::Null) | None => continue,
+            Some(value) => value,
+        };
+        let value = match MERGED_SECTIONS.iter().position(|s| s == field) {
+            Some(index) => order_section(value, &orders[index]),
+            None => value,
+        };
+        flattened.insert(field.to_string(), value);
+    }
+    Ok(Value::Object(flattened))
+}
+
+/// Flattens a .tsconfig file like [flatten_file], and renders it as `tsc --showConfig` does.
+///
+/// ## Example
+/// ```
+/// use std::path::Path;
+/// use tsconfig::show_config;
+///
+/// let path = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+///     .join("test/tsconfig.inherits.json");
+/// let output = show_config(&path).unwrap();
+///
+/// assert!(output.starts_with("{\n    \"compilerOptions\": {\n"));
+/// ```
+pub fn show_config<P: AsRef<Path>>(path: &P) -> Result<String> {
+    let value = flatten_file(path)?;
+
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    value.serialize(&mut serde_json::Serializer::with_formatter(
+        &mut out, formatter,
+    ))?;
+    out.push(b'\n');
+    Ok(String::from_utf8(out).expect("serde_json writes UTF-8"))
+}
+
+/// The keys of `section` across the chain, in the order they are first set, bases first.
+fn option_order(layers: &[Layer], section: &str) -> Vec<String> {
+    let mut order: Vec<String> = Vec::new();
+    for layer in layers {
+        if let Some(Value::Object(options)) = layer.value.get(section) {
+            for key in options.keys() {
+                if !order.contains(key) {
+                    order.push(key.clone());
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Reorders the keys of a merged section, dropping those set to `null`.
+fn order_section(section: Value, order: &[String]) -> Value {
+    let mut section = match section {
+        Value::Object(section) => section,
+        other => return other,
+    };
+    let mut ordered = Map::new();
+    for key in order {
+        match section.remove(key) {
+            Some(Value::Null) | None => {}
+            Some(value) => {
+                ordered.insert(key.clone(), value);
+            }
+        }
+    }
+    Value::Object(ordered)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    fn test_dir() -> PathBuf {
+        Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("test")
+    }
+
+    #[test]
+    fn flattens_rebased_chain() {
+        let config = flatten_file(&test_dir().join("rebase/tsconfig.json")).unwrap();
+        let expected =
+            crate::parse_file_to_value(&test_dir().join("rebase/tsconfig.json")).unwrap();
+
+        assert!(config.get("extends").is_none());
+        assert_eq!(config["compilerOptions"], expected["compilerOptions"]);
+        assert_eq!(config["include"], expected["include"]);
+    }
+
+    #[test]
+    fn orders_options_bases_first() {
+        let config = flatten_file(&test_dir().join("tsconfig.inherits.json")).unwrap();
+        let options: Vec<&String> = config["compilerOptions"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect();
+
+        assert_eq!(
+            options,
+            [
+                "useDefineForClassFields",
+                "traceResolution",
+                "jsx",
+                "declaration"
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_only_fields_tsc_shows() {
+        let config = flatten_file(&test_dir().join("merge/tsconfig.json")).unwrap();
+        let fields: Vec<&String> = config.as_object().unwrap().keys().collect();
+
+        assert_eq!(
+            fields,
+            [
+                "compilerOptions",
+                "files",
+                "include",
+                "exclude",
+                "compileOnSave"
+            ]
+        );
+        assert_eq!(config["exclude"], json!(["./dist"]));
+    }
+
+    #[test]
+    fn shows_config_with_tsc_indentation() {
+        let output = show_config(&test_dir().join("tsconfig.inherits.json")).unwrap();
+
+        assert_eq!(
+            output,
+            "{\n    \"compilerOptions\": {\n        \"useDefineForClassFields\": false,\n        \"traceResolution\": false,\n        \"jsx\": \"react-jsxdev\",\n        \"declaration\": true\n    }\n}\n"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! TypeScript versions, and the versions in which compiler options were introduced or removed.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::CompilerOptions;
+
+/// A TypeScript release, e.g. `5.4`. Patch releases never change options, so they are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TsVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl TsVersion {
+    /// The latest version this crate knows the options and defaults of.
+    pub const LATEST: TsVersion = TsVersion::new(5, 8);
+
+    pub const fn new(major: u32, minor: u32) -> Self {
+        TsVersion { major, minor }
+    }
+}
+
+impl Default for TsVersion {
+    fn default() -> Self {
+        TsVersion::LATEST
+    }
+}
+
+impl fmt::Display for TsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The error returned when a string is not a version like `5.4` or `5.4.2`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid TypeScript version '{0}'")]
+pub struct ParseVersionError(String);
+
+/// Parses `major.minor`, optionally followed by a patch number and a pre-release tag, as in
+/// `5.4.0-beta`.
+impl FromStr for TsVersion {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseVersionError(s.to_string());
+        let mut parts = s.trim_start_matches('v').splitn(3, '.');
+        let major = parts
+            .next()
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(error)?;
+        let minor = parts
+            .next()
+            .and_then(|p| p.split('-').next()?.parse().ok())
+            .ok_or_else(error)?;
+        Ok(TsVersion::new(major, minor))
+    }
+}
+
+/// A compiler option, or one value of it, that does not exist in a given TypeScript version.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum VersionIssue {
+    #[error("{} requires TypeScript {introduced} or later", describe(.option, .value))]
+    NotYetAvailable {
+        option: String,
+        /// The value, if only this value of the option is unavailable.
+        value: Option<String>,
+        introduced: TsVersion,
+    },
+    #[error("{} was removed in TypeScript {removed}", describe(.option, .value))]
+    Removed {
+        option: String,
+        /// The value, if only this value of the option was removed.
+        value: Option<String>,
+        removed: TsVersion,
+    },
+}
+
+fn describe(option: &str, value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Option '{}' value '{}'", option, value),
+        None => format!("Option '{}'", option),
+    }
+}
+
+/// An option, or a value of an option if the second field is set, with the version that
+/// introduced it and the version that removed it, if any.
+type Availability = (
+    &'static str,
+    Option<&'static str>,
+    TsVersion,
+    Option<TsVersion>,
+);
+
+const fn v(major: u32, minor: u32) -> TsVersion {
+    TsVersion::new(major, minor)
+}
+
+/// When options and values appeared and disappeared. Options missing from this table have been
+/// available since before TypeScript 2.0.
+const AVAILABILITY: &[Availability] = &[
+    ("allowArbitraryExtensions", None, v(5, 0), None),
+    ("allowImportingTsExtensions", None, v(5, 0), None),
+    ("allowSyntheticDefaultImports", None, v(1, 8), None),
+    ("allowUmdGlobalAccess", None, v(3, 5), None),
+    (
+        "assumeChangesOnlyAffectDirectDependencies",
+        None,
+        v(3, 8),
+        None,
+    ),
+    ("baseUrl", None, v(2, 0), None),
+    ("charset", None, v(1, 0), Some(v(5, 5))),
+    ("checkJs", None, v(2, 3), None),
+    ("composite", None, v(3, 0), None),
+    ("customConditions", None, v(5, 0), None),
+    ("declarationMap", None, v(2, 9), None),
+    ("disableReferencedProjectLoad", None, v(4, 0), None),
+    ("disableSolutionSearching", None, v(3, 8), None),
+    (
+        "disableSourceOfProjectReferenceRedirect",
+        None,
+        v(3, 7),
+        None,
+    ),
+    ("downlevelIteration", None, v(2, 3), None),
+    ("emitDeclarationOnly", None, v(2, 8), None),
+    ("erasableSyntaxOnly", None, v(5, 8), None),
+    ("esModuleInterop", None, v(2, 7), None),
+    ("exactOptionalPropertyTypes", None, v(4, 4), None),
+    ("explainFiles", None, v(4, 2), None),
+    ("generateCpuProfile", None, v(3, 7), None),
+    ("generateTrace", None, v(4, 1), None),
+    ("ignoreDeprecations", None, v(5, 0), None),
+    ("importHelpers", None, v(2, 1), None),
+    ("importsNotUsedAsValues", None, v(3, 8), Some(v(5, 5))),
+    ("incremental", None, v(3, 4), None),
+    ("isolatedDeclarations", None, v(5, 5), None),
+    ("jsxFragmentFactory", None, v(4, 0), None),
+    ("jsxImportSource", None, v(4, 1), None),
+    ("keyofStringsOnly", None, v(2, 9), Some(v(5, 5))),
+    ("lib", None, v(2, 0), None),
+    ("libReplacement", None, v(5, 8), None),
+    ("moduleDetection", None, v(4, 7), None),
+    ("moduleSuffixes", None, v(4, 7), None),
+    ("noCheck", None, v(5, 6), None),
+    ("noImplicitOverride", None, v(4, 3), None),
+    ("noImplicitUseStrict", None, v(1, 0), Some(v(5, 5))),
+    ("noPropertyAccessFromIndexSignature", None, v(4, 2), None),
+    ("noStrictGenericChecks", None, v(2, 4), Some(v(5, 5))),
+    ("noUncheckedIndexedAccess", None, v(4, 1), None),
+    ("noUncheckedSideEffectImports", None, v(5, 6), None),
+    ("out", None, v(1, 0), Some(v(5, 5))),
+    ("paths", None, v(2, 0), None),
+    ("preserveValueImports", None, v(4, 5), Some(v(5, 5))),
+    ("resolveJsonModule", None, v(2, 9), None),
+    ("resolvePackageJsonExports", None, v(5, 0), None),
+    ("resolvePackageJsonImports", None, v(5, 0), None),
+    ("rewriteRelativeImportExtensions", None, v(5, 7), None),
+    ("rootDirs", None, v(2, 0), None),
+    ("stopBuildOnErrors", None, v(5, 6), None),
+    ("strict", None, v(2, 3), None),
+    ("strictBindCallApply", None, v(3, 2), None),
+    ("strictBuiltinIteratorReturn", None, v(5, 6), None),
+    ("strictFunctionTypes", None, v(2, 6), None),
+    ("strictPropertyInitialization", None, v(2, 7), None),
+    ("suppressExcessPropertyErrors", None, v(1, 0), Some(v(5, 5))),
+    (
+        "suppressImplicitAnyIndexErrors",
+        None,
+        v(1, 0),
+        Some(v(5, 5)),
+    ),
+    ("tsBuildInfoFile", None, v(3, 4), None),
+    ("typeRoots", None, v(2, 0), None),
+    ("types", None, v(2, 0), None),
+    ("useDefineForClassFields", None, v(3, 7), None),
+    ("useUnknownInCatchVariables", None, v(4, 4), None),
+    ("verbatimModuleSyntax", None, v(5, 0), None),
+    ("jsx", Some("react-jsx"), v(4, 1), None),
+    ("jsx", Some("react-jsxdev"), v(4, 1), None),
+    ("module", Some("es2020"), v(3, 8), None),
+    ("module", Some("es2022"), v(4, 5), None),
+    ("module", Some("node16"), v(4, 7), None),
+    ("module", Some("nodenext"), v(4, 7), None),
+    ("module", Some("preserve"), v(5, 4), None),
+    ("module", Some("node18"), v(5, 8), None),
+    ("moduleResolution", Some("bundler"), v(5, 0), None),
+    ("moduleResolution", Some("node10"), v(5, 0), None),
+    ("moduleResolution", Some("node16"), v(4, 7), None),
+    ("moduleResolution", Some("nodenext"), v(4, 7), None),
+    ("target", Some("es3"), v(1, 0), Some(v(5, 5))),
+    ("target", Some("es2019"), v(3, 6), None),
+    ("target", Some("es2020"), v(3, 8), None),
+    ("target", Some("es2021"), v(4, 2), None),
+    ("target", Some("es2022"), v(4, 6), None),
+    ("target", Some("es2023"), v(5, 0), None),
+    ("target", Some("es2024"), v(5, 7), None),
+];
+
+impl CompilerOptions {
+    /// Reports the options set here that do not exist in the given TypeScript version, either
+    /// because they were introduced later or because they have been removed.
+    ///
+    /// ## Example
+    /// ```
+    /// use tsconfig::{TsConfig, TsVersion};
+    ///
+    /// let config = TsConfig::parse_str(r#"{"compilerOptions": {"moduleResolution": "bundler"}}"#).unwrap();
+    /// let issues = config.compiler_options.unwrap().check_version(TsVersion::new(4, 9));
+    ///
+    /// assert_eq!(
+    ///     issues[0].to_string(),
+    ///     "Option 'moduleResolution' value 'bundler' requires TypeScript 5.0 or later"
+    /// );
+    /// ```
+    pub fn check_version(&self, version: TsVersion) -> Vec<VersionIssue> {
+        let options = match serde_json::to_value(self) {
+            Ok(Value::Object(options)) => options,
+            _ => return Vec::new(),
+        };
+
+        let mut issues = Vec::new();
+        for (option, value) in &options {
+            let value = value.as_str().map(str::to_lowercase);
+            let matching = AVAILABILITY.iter().filter(|(name, only, _, _)| {
+                name == option && only.map_or(true, |only| value.as_deref() == Some(only))
+            });
+            for (_, only, introduced, removed) in matching {
+                let value = only.map(str::to_string);
+                if version < *introduced {
+                    issues.push(VersionIssue::NotYetAvailable {
+                        option: option.clone(),
+                        value,
+                        introduced: *introduced,
+                    });
+                } else if let Some(removed) = removed.filter(|removed| version >= *removed) {
+                    issues.push(VersionIssue::Removed {
+                        option: option.clone(),
+                        value,
+                        removed,
+                    });
+                }
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TsConfig;
+
+    fn issues(json: &str, version: &str) -> Vec<VersionIssue> {
+        TsConfig::parse_str(json)
+            .unwrap()
+            .compiler_options
+            .unwrap()
+            .check_version(version.parse().unwrap())
+    }
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!("5.4".parse(), Ok(TsVersion::new(5, 4)));
+        assert_eq!("4.9.5".parse(), Ok(TsVersion::new(4, 9)));
+        assert_eq!("5.5-beta".parse(), Ok(TsVersion::new(5, 5)));
+        assert_eq!("v5.0.0-dev.1".parse(), Ok(TsVersion::new(5, 0)));
+        assert!("5".parse::<TsVersion>().is_err());
+        assert!("latest".parse::<TsVersion>().is_err());
+        assert!(TsVersion::new(4, 10) > TsVersion::new(4, 9));
+        assert_eq!(TsVersion::new(5, 4).to_string(), "5.4");
+    }
+
+    #[test]
+    fn reports_options_not_yet_available() {
+        let json = r#"{"compilerOptions": {
+            "moduleResolution": "bundler",
+            "noUncheckedIndexedAccess": true,
+            "strict": true
+        }}"#;
+
+        assert_eq!(
+            issues(json, "4.0"),
+            vec![
+                VersionIssue::NotYetAvailable {
+                    option: "moduleResolution".to_string(),
+                    value: Some("bundler".to_string()),
+                    introduced: TsVersion::new(5, 0),
+                },
+                VersionIssue::NotYetAvailable {
+                    option: "noUncheckedIndexedAccess".to_string(),
+                    value: None,
+                    introduced: TsVersion::new(4, 1),
+                },
+            ]
+        );
+        assert!(issues(json, "5.0").is_empty());
+    }
+
+    #[test]
+    fn reports_removed_options() {
+        let json = r#"{"compilerOptions": {"target": "ES3", "keyofStringsOnly": true}}"#;
+
+        assert!(issues(json, "5.4").is_empty());
+        let removed = issues(json, "5.5");
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            removed[0].to_string(),
+            "Option 'target' value 'es3' was removed in TypeScript 5.5"
+        );
+        assert_eq!(
+            removed[1].to_string(),
+            "Option 'keyofStringsOnly' was removed in TypeScript 5.5"
+        );
+    }
+}